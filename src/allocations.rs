@@ -0,0 +1,130 @@
+use std::fmt;
+#[cfg(feature = "count-allocations")]
+use std::alloc::{GlobalAlloc, Layout, System};
+#[cfg(feature = "count-allocations")]
+use std::cell::Cell;
+
+/// What a measured piece of work allocated on its thread.
+#[derive(Eq, PartialEq, Clone, Copy, Debug, Default)]
+pub struct AllocationStats {
+    /// Number of allocations, a reallocation counts as one.
+    pub allocations: usize,
+    /// Sum of the sizes of all allocations.
+    pub bytes: usize,
+    /// Highest number of live bytes, on top of those that were live when the measurement started.
+    pub peak: usize
+}
+
+impl fmt::Display for AllocationStats {
+    fn fmt (&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} allocations, {} total, {} peak", self.allocations, format_bytes(self.bytes), format_bytes(self.peak))
+    }
+}
+
+fn format_bytes (bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit + 1 < UNITS.len() {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", size, UNITS[unit])
+}
+
+#[cfg(feature = "count-allocations")]
+#[derive(Clone, Copy)]
+struct Counters {
+    allocations: usize,
+    bytes: usize,
+    live: usize,
+    peak: usize
+}
+
+// The counters are kept per thread, so days solved in parallel don't count each other's allocations.
+#[cfg(feature = "count-allocations")]
+thread_local! {
+    static COUNTERS: Cell<Counters> = const { Cell::new(Counters { allocations: 0, bytes: 0, live: 0, peak: 0 }) };
+}
+
+#[cfg(feature = "count-allocations")]
+fn record_allocation (size: usize) {
+    let _ = COUNTERS.try_with(|counters| {
+        let mut current = counters.get();
+        current.allocations += 1;
+        current.bytes += size;
+        current.live += size;
+        current.peak = current.peak.max(current.live);
+        counters.set(current);
+    });
+}
+
+#[cfg(feature = "count-allocations")]
+fn record_deallocation (size: usize) {
+    let _ = COUNTERS.try_with(|counters| {
+        let mut current = counters.get();
+        // memory may have been allocated by another thread
+        current.live = current.live.saturating_sub(size);
+        counters.set(current);
+    });
+}
+
+/// Forwards to the system allocator and counts on the way.
+#[cfg(feature = "count-allocations")]
+pub struct CountingAllocator;
+
+#[cfg(feature = "count-allocations")]
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc (&self, layout: Layout) -> *mut u8 {
+        record_allocation(layout.size());
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed (&self, layout: Layout) -> *mut u8 {
+        record_allocation(layout.size());
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn dealloc (&self, ptr: *mut u8, layout: Layout) {
+        record_deallocation(layout.size());
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc (&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        record_deallocation(layout.size());
+        record_allocation(new_size);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Runs `work` and returns what it allocated on the current thread.
+#[cfg(feature = "count-allocations")]
+pub fn measure<T> (work: impl FnOnce() -> T) -> (T, Option<AllocationStats>) {
+    let start = COUNTERS.with(|counters| {
+        let mut current = counters.get();
+        current.peak = current.live;
+        counters.set(current);
+        current
+    });
+    let result = work();
+    let end = COUNTERS.with(Cell::get);
+    let stats = AllocationStats {
+        allocations: end.allocations - start.allocations,
+        bytes: end.bytes - start.bytes,
+        peak: end.peak - start.live
+    };
+    (result, Some(stats))
+}
+
+/// Without the `count-allocations` feature nothing is counted.
+#[cfg(not(feature = "count-allocations"))]
+pub fn measure<T> (work: impl FnOnce() -> T) -> (T, Option<AllocationStats>) {
+    (work(), None)
+}
@@ -0,0 +1,84 @@
+use colored::Colorize;
+use std::fmt;
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    /// Rows of pixels, `true` being a lit pixel. The puzzle answer is the text the pixels spell.
+    Image(Vec<Vec<bool>>),
+    RequiresInteraction
+}
+
+impl Answer {
+    /// Single line representation used to store and compare answers. Image rows are rendered
+    /// with `#` for lit and `.` for dark pixels and joined by `/`.
+    pub fn as_line (&self) -> String {
+        match self {
+            Answer::Number(number) => number.to_string(),
+            Answer::Text(text) => text.clone(),
+            Answer::Image(rows) => rows
+                .iter()
+                .map(|row| row.iter().map(|lit| if *lit { '#' } else { '.' }).collect::<String>())
+                .collect::<Vec<String>>()
+                .join("/"),
+            Answer::RequiresInteraction => "requires interaction".to_string()
+        }
+    }
+
+    pub fn matches (&self, expected: &str) -> bool {
+        self.as_line() == expected.trim()
+    }
+
+    /// Terminal representation, images are drawn as colored blocks.
+    pub fn render (&self) -> String {
+        match self {
+            Answer::Image(rows) => rows
+                .iter()
+                .map(|row| row
+                    .iter()
+                    .map(|lit| if *lit { "█".purple().to_string() } else { "█".cyan().to_string() })
+                    .collect::<String>())
+                .collect::<Vec<String>>()
+                .join("\n"),
+            _ => self.to_string()
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt (&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Image(_) => write!(f, "{}", self.as_line().replace('/', "\n")),
+            _ => write!(f, "{}", self.as_line())
+        }
+    }
+}
+
+impl From<i32> for Answer {
+    fn from (number: i32) -> Self { Answer::Number(number as i128) }
+}
+
+impl From<i64> for Answer {
+    fn from (number: i64) -> Self { Answer::Number(number as i128) }
+}
+
+impl From<i128> for Answer {
+    fn from (number: i128) -> Self { Answer::Number(number) }
+}
+
+impl From<u32> for Answer {
+    fn from (number: u32) -> Self { Answer::Number(number as i128) }
+}
+
+impl From<usize> for Answer {
+    fn from (number: usize) -> Self { Answer::Number(number as i128) }
+}
+
+impl From<String> for Answer {
+    fn from (text: String) -> Self { Answer::Text(text) }
+}
+
+impl From<&str> for Answer {
+    fn from (text: &str) -> Self { Answer::Text(text.to_string()) }
+}
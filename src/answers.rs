@@ -0,0 +1,64 @@
+use crate::day_tasks::Part;
+use std::collections::HashMap;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+
+/// The expected results of one input, read from a file with one `<part>: <answer>` line per part.
+/// Empty lines and lines starting with `#` are ignored.
+#[derive(Eq, PartialEq, Clone, Debug, Default)]
+pub struct KnownAnswers {
+    answers: HashMap<u32, String>
+}
+
+impl KnownAnswers {
+    pub fn get (&self, part: Part) -> Option<&str> {
+        self.answers.get(&part.number()).map(|answer| answer.as_str())
+    }
+}
+
+pub fn parse (text: &str) -> Result<KnownAnswers, String> {
+    let mut answers = HashMap::new();
+    for (index, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') { continue; }
+        let (part, answer) = line
+            .split_once(':')
+            .ok_or_else(|| format!("line {}: expected '<part>: <answer>'", index + 1))?;
+        let part = match part.trim() {
+            "1" => 1,
+            "2" => 2,
+            other => return Err(format!("line {}: '{}' is not a part", index + 1, other))
+        };
+        answers.insert(part, answer.trim().to_string());
+    }
+    Ok(KnownAnswers { answers })
+}
+
+/// A missing answers file is not an error, it just means that no answers are known yet.
+pub fn load (path: &Path) -> Result<KnownAnswers, String> {
+    match fs::read_to_string(path) {
+        Ok(text) => parse(&text).map_err(|message| format!("{}: {}", path.display(), message)),
+        Err(error) if error.kind() == ErrorKind::NotFound => Ok(KnownAnswers::default()),
+        Err(error) => Err(format!("couldn't read answers file '{}': {}", path.display(), error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_one_answer_per_part () {
+        let known_answers = parse("# my input\n1: 3334297\n\n2:  4998565 \n").unwrap();
+        assert_eq!(known_answers.get(Part::First), Some("3334297"));
+        assert_eq!(known_answers.get(Part::Second), Some("4998565"));
+        assert_eq!(parse("2: ABCD").unwrap().get(Part::First), None);
+    }
+
+    #[test]
+    fn rejects_malformed_lines () {
+        assert_eq!(parse("1: 5\n3334297"), Err("line 2: expected '<part>: <answer>'".to_string()));
+        assert_eq!(parse("3: 5"), Err("line 1: '3' is not a part".to_string()));
+    }
+}
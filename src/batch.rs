@@ -0,0 +1,169 @@
+use crate::answer::Answer;
+use crate::answers::{self, KnownAnswers};
+use crate::day_tasks::{Part, Solution};
+use crate::input::{InputSource, InputStore};
+use crate::verify::{self, Status};
+use crate::watchdog;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// How one part did on one input of the batch.
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct PartOutcome {
+    pub part: Part,
+    pub answer: Option<Answer>,
+    pub duration: Duration,
+    pub status: Status
+}
+
+/// One input of the batch, `parse_duration` is `None` if the input couldn't be read.
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct BatchRow {
+    pub path: PathBuf,
+    pub parse_duration: Option<Duration>,
+    pub outcomes: Vec<PartOutcome>
+}
+
+impl BatchRow {
+    pub fn is_regression (&self) -> bool {
+        self.outcomes.iter().any(|outcome| matches!(outcome.status, Status::Fail { .. } | Status::Error(_)))
+    }
+}
+
+/// The `*.txt` files in `directory` sorted by name, each with its known answers in `<name>.answers` next to it.
+pub fn input_files (directory: &Path) -> Result<Vec<PathBuf>, String> {
+    let entries = fs::read_dir(directory)
+        .map_err(|error| format!("couldn't read input directory '{}': {}", directory.display(), error))?;
+    let mut files = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.is_file() && path.extension().is_some_and(|extension| extension == "txt"))
+        .collect::<Vec<PathBuf>>();
+    files.sort();
+    Ok(files)
+}
+
+/// Solves `day` for every file and judges the answers by the file's known answers.
+pub fn run (day: &Arc<dyn Solution>, files: &[PathBuf], parts: &[Part], timeout: Option<Duration>) -> Vec<BatchRow> {
+    files.iter().map(|path| run_file(day, path, parts, timeout)).collect()
+}
+
+fn run_file (day: &Arc<dyn Solution>, path: &Path, parts: &[Part], timeout: Option<Duration>) -> BatchRow {
+    let (year, day_number) = (day.year(), day.day_number());
+    let failed = |parse_duration: Option<Duration>, status: Status| BatchRow {
+        path: path.to_path_buf(),
+        parse_duration,
+        outcomes: parts
+            .iter()
+            .map(|part| PartOutcome { part: *part, answer: None, duration: Duration::default(), status: status.clone() })
+            .collect()
+    };
+    let input = InputStore::new(InputSource::File(path.to_path_buf()), None);
+    let contents = match input.read(&year, &day_number) {
+        Ok(contents) => contents,
+        Err(message) => return failed(None, Status::NoInput(message))
+    };
+    let known_answers = match input.answers_path(&year, &day_number).map(|path| answers::load(&path)) {
+        Some(Ok(known_answers)) => known_answers,
+        None => KnownAnswers::default(),
+        Some(Err(message)) => return failed(None, Status::Error(message))
+    };
+    let day_run = watchdog::solve(day, contents, parts, false, timeout);
+    let part_runs = match day_run.parts {
+        Ok(part_runs) => part_runs,
+        Err(error) => return failed(Some(day_run.parse_duration), Status::Error(error.to_string()))
+    };
+    let outcomes = part_runs
+        .into_iter()
+        .map(|part_run| {
+            let (answer, status) = match part_run.result {
+                Ok(answer) => {
+                    let status = verify::judge(&answer, known_answers.get(part_run.part));
+                    (Some(answer), status)
+                }
+                Err(error) => (None, Status::Error(error.to_string()))
+            };
+            PartOutcome { part: part_run.part, answer, duration: part_run.duration, status }
+        })
+        .collect();
+    BatchRow { path: path.to_path_buf(), parse_duration: Some(day_run.parse_duration), outcomes }
+}
+
+/// Prints one row per input with the status, answer and time of each part.
+pub fn print_report (rows: &[BatchRow], parts: &[Part]) {
+    let mut table = vec![
+        vec!["Input".to_string(), "Parse".to_string()]
+            .into_iter()
+            .chain(parts.iter().map(|part| format!("Part {}", part.number())))
+            .collect::<Vec<String>>()
+    ];
+    for row in rows {
+        let name = row.path.file_name().unwrap_or(row.path.as_os_str()).to_string_lossy().to_string();
+        let parse_time = row.parse_duration.map(|duration| format!("{:.2?}", duration)).unwrap_or_default();
+        table.push(vec![name, parse_time].into_iter().chain(row.outcomes.iter().map(describe)).collect());
+    }
+    let column_count = table.iter().map(|cells| cells.len()).max().unwrap_or(0);
+    let widths = (0..column_count)
+        .map(|column| table.iter().filter_map(|cells| cells.get(column)).map(|cell| cell.chars().count()).max().unwrap_or(0))
+        .collect::<Vec<usize>>();
+    for cells in &table {
+        let line = cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect::<Vec<String>>()
+            .join("  ");
+        println!("{}", line.trim_end());
+    }
+    println!("{} inputs, {} with failures", rows.len(), rows.iter().filter(|row| row.is_regression()).count());
+}
+
+fn describe (outcome: &PartOutcome) -> String {
+    let answer = outcome.answer.as_ref().map(|answer| answer.as_line()).unwrap_or_default();
+    let time = format!("{:.2?}", outcome.duration);
+    match &outcome.status {
+        Status::Pass => format!("pass {} in {}", answer, time),
+        Status::Fail { expected } => format!("FAIL {} in {}, expected {}", answer, time, expected),
+        Status::Missing => format!("{} in {}", answer, time),
+        Status::Interactive => "skipped".to_string(),
+        Status::NoInput(message) => format!("no input: {}", message),
+        Status::Error(message) => format!("ERROR {}", message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::years::y2019::days::day_01::Day01;
+    use std::env;
+    use std::process;
+
+    #[test]
+    fn judges_each_input_by_its_answers_file () {
+        let directory = env::temp_dir().join(format!("advent_of_code_rust-batch-{}", process::id()));
+        let _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(&directory).unwrap();
+        fs::write(directory.join("a.txt"), "12\n14\n").unwrap();
+        fs::write(directory.join("a.answers"), "1: 4\n2: 4\n").unwrap();
+        fs::write(directory.join("b.txt"), "1969\n").unwrap();
+        fs::write(directory.join("b.answers"), "1: 654\n2: 900\n").unwrap();
+        fs::write(directory.join("c.txt"), "100756\n").unwrap();
+
+        let files = input_files(&directory).unwrap();
+        assert_eq!(files, vec![directory.join("a.txt"), directory.join("b.txt"), directory.join("c.txt")]);
+        let day: Arc<dyn Solution> = Arc::new(Day01);
+        let rows = run(&day, &files, &[Part::First, Part::Second], None);
+        let statuses = rows
+            .iter()
+            .map(|row| row.outcomes.iter().map(|outcome| outcome.status.clone()).collect::<Vec<Status>>())
+            .collect::<Vec<Vec<Status>>>();
+        assert_eq!(statuses, vec![
+            vec![Status::Pass, Status::Pass],
+            vec![Status::Pass, Status::Fail { expected: "900".to_string() }],
+            vec![Status::Missing, Status::Missing]
+        ]);
+        assert_eq!(rows.iter().map(BatchRow::is_regression).collect::<Vec<bool>>(), vec![false, true, false]);
+        let _ = fs::remove_dir_all(&directory);
+    }
+}
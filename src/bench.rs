@@ -0,0 +1,253 @@
+use crate::day_tasks::{Part, Solution};
+use crate::input::InputStore;
+use std::collections::HashMap;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct BenchOptions {
+    pub warmup: u32,
+    pub iterations: u32,
+    /// Compare the medians with this baseline file.
+    pub baseline: Option<PathBuf>,
+    /// Write the medians of this run to this baseline file.
+    pub save_baseline: Option<PathBuf>,
+    /// How many percent slower than the baseline a median may be before it counts as regression.
+    pub threshold: u32
+}
+
+impl Default for BenchOptions {
+    fn default () -> Self {
+        BenchOptions { warmup: 2, iterations: 10, baseline: None, save_baseline: None, threshold: 10 }
+    }
+}
+
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub enum Phase {
+    Parse,
+    Part(Part)
+}
+
+impl Phase {
+    pub fn label (&self) -> String {
+        match self {
+            Phase::Parse => "parse".to_string(),
+            Phase::Part(part) => part.number().to_string()
+        }
+    }
+}
+
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub struct Statistics {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration
+}
+
+impl Statistics {
+    /// `samples` must not be empty.
+    pub fn from_samples (samples: &[Duration]) -> Statistics {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let count = sorted.len();
+        let median = if count.is_multiple_of(2) { (sorted[count / 2 - 1] + sorted[count / 2]) / 2 } else { sorted[count / 2] };
+        let mean = sorted.iter().sum::<Duration>() / count as u32;
+        let variance = sorted
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>() / count as f64;
+        Statistics { min: sorted[0], median, mean, stddev: Duration::from_secs_f64(variance.sqrt()) }
+    }
+}
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct Benchmark {
+    pub year: String,
+    pub day_number: String,
+    pub phase: Phase,
+    pub statistics: Result<Statistics, String>,
+    pub baseline: Option<Duration>
+}
+
+impl Benchmark {
+    pub fn is_regression (&self, threshold: u32) -> bool {
+        match (&self.statistics, self.baseline) {
+            (Ok(statistics), Some(baseline)) => statistics.median.as_secs_f64() > baseline.as_secs_f64() * (100 + threshold) as f64 / 100.0,
+            _ => false
+        }
+    }
+
+    fn key (&self) -> (String, String, String) {
+        (self.year.clone(), self.day_number.clone(), self.phase.label())
+    }
+}
+
+pub fn bench (days: &[Arc<dyn Solution>], input: &InputStore, parts: &[Part], options: &BenchOptions) -> Vec<Benchmark> {
+    let mut benchmarks = Vec::new();
+    for day in days {
+        let (year, day_number) = (day.year(), day.day_number());
+        let benchmark = |phase: Phase, statistics: Result<Statistics, String>| Benchmark {
+            year: year.clone(),
+            day_number: day_number.clone(),
+            phase,
+            statistics,
+            baseline: None
+        };
+        let contents = match input.read(&year, &day_number) {
+            Ok(contents) => contents,
+            Err(message) => { benchmarks.push(benchmark(Phase::Parse, Err(message))); continue; }
+        };
+        for _ in 0..options.warmup {
+            day.solve(&contents, parts, false);
+        }
+        let mut parse_samples = Vec::new();
+        let mut part_samples: Vec<Result<Vec<Duration>, String>> = parts.iter().map(|_| Ok(Vec::new())).collect();
+        let mut parse_error = None;
+        for _ in 0..options.iterations {
+            let day_run = day.solve(&contents, parts, false);
+            parse_samples.push(day_run.parse_duration);
+            match day_run.parts {
+                Ok(part_runs) => {
+                    for (samples, part_run) in part_samples.iter_mut().zip(part_runs) {
+                        match (samples.as_mut(), part_run.result) {
+                            (Ok(samples), Ok(_)) => samples.push(part_run.duration),
+                            (Ok(_), Err(error)) => *samples = Err(error.to_string()),
+                            (Err(_), _) => {}
+                        }
+                    }
+                }
+                Err(error) => { parse_error = Some(error.to_string()); break; }
+            }
+        }
+        if let Some(error) = parse_error {
+            benchmarks.push(benchmark(Phase::Parse, Err(error)));
+            continue;
+        }
+        benchmarks.push(benchmark(Phase::Parse, Ok(Statistics::from_samples(&parse_samples))));
+        for (part, samples) in parts.iter().zip(part_samples) {
+            benchmarks.push(benchmark(Phase::Part(*part), samples.map(|samples| Statistics::from_samples(&samples))));
+        }
+    }
+    benchmarks
+}
+
+/// Median durations of an earlier run, keyed by year, day and phase.
+pub type Baseline = HashMap<(String, String, String), Duration>;
+
+pub fn apply_baseline (benchmarks: &mut [Benchmark], baseline: &Baseline) {
+    for benchmark in benchmarks {
+        benchmark.baseline = baseline.get(&benchmark.key()).copied();
+    }
+}
+
+/// Baseline files hold one `<year> <day> <phase> <median in nanoseconds>` line per benchmark.
+pub fn parse_baseline (text: &str) -> Result<Baseline, String> {
+    let mut baseline = HashMap::new();
+    for (index, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') { continue; }
+        match line.split_whitespace().collect::<Vec<&str>>()[..] {
+            [year, day_number, phase, nanos] => {
+                let nanos = nanos
+                    .parse::<u64>()
+                    .map_err(|_| format!("line {}: '{}' is not a number of nanoseconds", index + 1, nanos))?;
+                baseline.insert((year.to_string(), day_number.to_string(), phase.to_string()), Duration::from_nanos(nanos));
+            }
+            _ => return Err(format!("line {}: expected '<year> <day> <phase> <nanoseconds>'", index + 1))
+        }
+    }
+    Ok(baseline)
+}
+
+/// A missing baseline file is not an error, there is just nothing to compare with yet.
+pub fn load_baseline (path: &Path) -> Result<Baseline, String> {
+    match fs::read_to_string(path) {
+        Ok(text) => parse_baseline(&text).map_err(|message| format!("{}: {}", path.display(), message)),
+        Err(error) if error.kind() == ErrorKind::NotFound => Ok(Baseline::new()),
+        Err(error) => Err(format!("couldn't read baseline file '{}': {}", path.display(), error))
+    }
+}
+
+/// Merges the medians of `benchmarks` into the baseline file, entries of days that weren't benchmarked are kept.
+pub fn save_baseline (path: &Path, benchmarks: &[Benchmark]) -> Result<(), String> {
+    let mut baseline = load_baseline(path)?;
+    for benchmark in benchmarks {
+        if let Ok(statistics) = &benchmark.statistics {
+            baseline.insert(benchmark.key(), statistics.median);
+        }
+    }
+    let mut lines = baseline
+        .into_iter()
+        .map(|((year, day_number, phase), median)| format!("{} {} {} {}", year, day_number, phase, median.as_nanos()))
+        .collect::<Vec<String>>();
+    lines.sort();
+    fs::write(path, lines.join("\n") + "\n")
+        .map_err(|error| format!("couldn't write baseline file '{}': {}", path.display(), error))
+}
+
+pub fn print_report (benchmarks: &[Benchmark], threshold: u32) {
+    fn format_duration (duration: Duration) -> String {
+        format!("{:.2?}", duration)
+    }
+
+    println!("{:<6}{:<5}{:<7}{:>12}{:>12}{:>12}{:>12}  Baseline", "Year", "Day", "Phase", "Min", "Median", "Mean", "Stddev");
+    for benchmark in benchmarks {
+        let statistics = match &benchmark.statistics {
+            Ok(statistics) => statistics,
+            Err(message) => {
+                println!("{:<6}{:<5}{:<7}{}", benchmark.year, benchmark.day_number, benchmark.phase.label(), message);
+                continue;
+            }
+        };
+        let comparison = match benchmark.baseline {
+            Some(baseline) => {
+                let change = (statistics.median.as_secs_f64() / baseline.as_secs_f64() - 1.0) * 100.0;
+                let flag = if benchmark.is_regression(threshold) { " REGRESSION" } else { "" };
+                format!("{} ({:+.1}%){}", format_duration(baseline), change, flag)
+            }
+            None => String::new()
+        };
+        println!("{:<6}{:<5}{:<7}{:>12}{:>12}{:>12}{:>12}  {}",
+            benchmark.year,
+            benchmark.day_number,
+            benchmark.phase.label(),
+            format_duration(statistics.min),
+            format_duration(statistics.median),
+            format_duration(statistics.mean),
+            format_duration(statistics.stddev),
+            comparison);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis (samples: &[u64]) -> Vec<Duration> {
+        samples.iter().map(|sample| Duration::from_millis(*sample)).collect()
+    }
+
+    #[test]
+    fn summarizes_samples () {
+        let statistics = Statistics::from_samples(&millis(&[6, 1, 3, 2]));
+        assert_eq!(statistics.min, Duration::from_millis(1));
+        assert_eq!(statistics.median, Duration::from_micros(2500));
+        assert_eq!(statistics.mean, Duration::from_millis(3));
+        assert_eq!(statistics.stddev.as_micros(), 1870);
+        assert_eq!(Statistics::from_samples(&millis(&[5, 1, 3])).median, Duration::from_millis(3));
+        assert_eq!(Statistics::from_samples(&millis(&[4])).stddev, Duration::ZERO);
+    }
+
+    #[test]
+    fn parses_baselines () {
+        let baseline = parse_baseline("# medians\n2019 01 parse 1500\n\n2019 01 2 250000\n").unwrap();
+        assert_eq!(baseline.len(), 2);
+        assert_eq!(baseline.get(&("2019".to_string(), "01".to_string(), "2".to_string())), Some(&Duration::from_micros(250)));
+        assert_eq!(parse_baseline("2019 01 parse"), Err("line 1: expected '<year> <day> <phase> <nanoseconds>'".to_string()));
+        assert_eq!(parse_baseline("2019 01 1 fast"), Err("line 1: 'fast' is not a number of nanoseconds".to_string()));
+    }
+}
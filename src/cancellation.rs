@@ -0,0 +1,32 @@
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Raised by the watchdog when it gives up on a worker. Threads can't be killed, so long running loops,
+/// like the one stepping an IntCode program, check the flag of the worker they run on and stop early.
+#[derive(Clone, Default, Debug)]
+pub struct Cancellation(Arc<AtomicBool>);
+
+impl Cancellation {
+    pub fn cancel (&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled (&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<Cancellation>> = const { RefCell::new(None) };
+}
+
+/// Work started on this thread from now on can be stopped with `cancellation`.
+pub fn install (cancellation: Cancellation) {
+    CURRENT.with(|current| *current.borrow_mut() = Some(cancellation));
+}
+
+/// Whether the worker running on this thread was given up on, never outside of a worker.
+pub fn is_cancelled () -> bool {
+    CURRENT.with(|current| current.borrow().as_ref().is_some_and(Cancellation::is_cancelled))
+}
@@ -0,0 +1,459 @@
+use advent_of_code_rust::bench::BenchOptions;
+use advent_of_code_rust::day_tasks::{Part, BOTH_PARTS};
+use advent_of_code_rust::guesses::{Feedback, Guess};
+use advent_of_code_rust::input::InputSource;
+use advent_of_code_rust::logging::Level;
+use advent_of_code_rust::years::{DaySelection, YearSelection};
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+use std::time::Duration;
+
+pub const USAGE: &str = "\
+Usage:
+    advent_of_code_rust run <days> [<options>]
+    advent_of_code_rust verify [<days>] [<options>]
+    advent_of_code_rust bench [<days>] [<options>] [<bench options>]
+    advent_of_code_rust batch <day> <DIR> [--year <YEAR>] [--part <1|2>] [--timeout <SECS>] [--log <LEVEL>]
+    advent_of_code_rust new-day <day> [--year <YEAR>] [--input-dir <DIR>]
+    advent_of_code_rust guess <day> [--year <YEAR>] [--input-dir <DIR>]
+        [--part <1|2> --answer <ANSWER> --feedback <too-high|too-low|wrong>]
+    advent_of_code_rust assemble <FILE>
+    advent_of_code_rust disassemble <FILE> [--source]
+    advent_of_code_rust debug <FILE>
+    advent_of_code_rust help
+
+Options:
+    --year <YEAR>      select the puzzle year
+    --part <1|2>       only run one part of each day
+    --input-dir <DIR>  read inputs from <DIR>
+    --input <FILE>     read the input of a single day from <FILE>
+    --jobs <N>         run: solve the days on <N> threads and print one table at the end
+    --format <FORMAT>  run: print the results as text (default), json or csv
+    --timeout <SECS>   run, verify: give up on parsing or a part after <SECS> seconds
+    --log <LEVEL>      write quiet, info (default), debug or trace diagnostics to stderr
+    --trace <FILE>     run: write every instruction the IntCode programs execute to <FILE>, not with --jobs
+    --trace-range <FROM..TO>
+                       run: only trace the instructions at addresses FROM to TO
+
+Bench options:
+    --warmup <N>              unmeasured runs before measuring (default 2)
+    --iterations <N>          measured runs per day (default 10)
+    --baseline <FILE>         compare the medians with a saved baseline
+    --save-baseline <FILE>    save the medians as baseline
+    --threshold <PERCENT>     slowdown against the baseline that counts as regression (default 10)
+
+<days> is either a single day (7), an inclusive range (3..12) or all.
+<YEAR> is a puzzle year (2019) or all, and defaults to the latest year with solutions.
+Inputs are read from <DIR>/<year>/<day>.txt, where <DIR> defaults to $AOC_INPUT_DIR
+or ./Input. --input reads a single day's input from <FILE>, or from stdin for '-'.
+verify compares the results with the known answers stored next to each input in
+<day>.answers (lines of the form '1: <answer>' and '2: <answer>') and fails on mismatches.
+batch solves a day for every <DIR>/*.txt, checks <DIR>/*.answers and prints one row per input.
+bench reports min/median/mean/stddev of parsing and each part and fails on regressions
+against the baseline.
+new-day generates src/years/y<year>/days/day_<day>.rs with an empty example test,
+registers it and creates an empty input file.
+guess records a rejected answer in <DIR>/<year>/<day>.guesses, or lists the recorded ones.
+run warns when an answer was already rejected or is out of the bounds the guesses give.
+assemble prints the IntCode program for the assembly in <FILE>.
+disassemble lists the IntCode program in <FILE> as instructions, with jump targets labeled
+and cells that don't decode marked as probable data. --source leaves out addresses and raw cells
+and writes data as directives, so the listing assembles back into the same program.
+debug runs the IntCode program in <FILE> in a debugger, type help there for its commands.";
+
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub enum OutputFormat {
+    Text,
+    Json,
+    Csv
+}
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct RunOptions {
+    pub years: YearSelection,
+    pub days: DaySelection,
+    pub parts: Vec<Part>,
+    pub input: Option<InputSource>,
+    pub jobs: Option<usize>,
+    pub format: OutputFormat,
+    /// How long parsing and each part may take, `None` waits forever.
+    pub timeout: Option<Duration>,
+    pub log_level: Level,
+    /// Where to trace the IntCode programs to and the addresses of the traced instructions.
+    pub trace: Option<(PathBuf, RangeInclusive<usize>)>
+}
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct NewDayOptions {
+    /// `None` selects the latest year with solutions.
+    pub year: Option<u32>,
+    pub day: u32,
+    pub input_dir: Option<PathBuf>
+}
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct GuessOptions {
+    /// `None` selects the latest year with solutions.
+    pub year: Option<u32>,
+    pub day: u32,
+    pub input_dir: Option<PathBuf>,
+    /// The guess to record, `None` lists the recorded guesses.
+    pub guess: Option<Guess>
+}
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub enum Command {
+    Run(RunOptions),
+    Verify(RunOptions),
+    Bench(RunOptions, BenchOptions),
+    /// Runs a single day on every input in a directory.
+    Batch(RunOptions, PathBuf),
+    NewDay(NewDayOptions),
+    Guess(GuessOptions),
+    Assemble(PathBuf),
+    /// Lists the IntCode program in a file, as assembly source if the flag is set.
+    Disassemble(PathBuf, bool),
+    Debug(PathBuf),
+    Help
+}
+
+pub fn parse (args: &[String]) -> Result<Command, String> {
+    match args.first().map(|arg| arg.as_str()) {
+        Some("run") => {
+            let days = args
+                .get(1)
+                .ok_or_else(|| "missing day selection".to_string())
+                .and_then(|text| parse_day_selection(text))?;
+            parse_run_options(days, &args[2..]).map(Command::Run)
+        }
+        Some("verify") => parse_optional_days(&args[1..]).and_then(reject_run_only_options).map(Command::Verify),
+        Some("bench") => {
+            let (bench_options, args) = parse_bench_options(&args[1..])?;
+            let options = parse_optional_days(&args).and_then(reject_run_only_options)?;
+            if options.timeout.is_some() {
+                return Err("--timeout can't be used with bench".to_string());
+            }
+            Ok(Command::Bench(options, bench_options))
+        }
+        Some("batch") => {
+            let day = args
+                .get(1)
+                .ok_or_else(|| "missing day".to_string())
+                .and_then(|text| parse_day(text))?;
+            let directory = args.get(2).ok_or_else(|| "missing input directory".to_string())?;
+            let options = parse_run_options(DaySelection::Range(day, day), &args[3..]).and_then(reject_run_only_options)?;
+            if options.input.is_some() {
+                return Err("--input and --input-dir can't be used with batch".to_string());
+            }
+            Ok(Command::Batch(options, PathBuf::from(directory)))
+        }
+        Some("new-day") => {
+            let day = args
+                .get(1)
+                .ok_or_else(|| "missing day".to_string())
+                .and_then(|text| parse_day(text))?;
+            parse_new_day_options(day, &args[2..]).map(Command::NewDay)
+        }
+        Some("guess") => {
+            let day = args
+                .get(1)
+                .ok_or_else(|| "missing day".to_string())
+                .and_then(|text| parse_day(text))?;
+            parse_guess_options(day, &args[2..]).map(Command::Guess)
+        }
+        Some("assemble") => match &args[1..] {
+            [path] => Ok(Command::Assemble(PathBuf::from(path))),
+            [] => Err("missing assembly file".to_string()),
+            [_, unknown, ..] => Err(format!("unexpected argument '{}'", unknown))
+        },
+        Some("debug") => match &args[1..] {
+            [path] => Ok(Command::Debug(PathBuf::from(path))),
+            [] => Err("missing program file".to_string()),
+            [_, unknown, ..] => Err(format!("unexpected argument '{}'", unknown))
+        },
+        Some("disassemble") => match &args[1..] {
+            [path] => Ok(Command::Disassemble(PathBuf::from(path), false)),
+            [path, flag] if flag == "--source" => Ok(Command::Disassemble(PathBuf::from(path), true)),
+            [] => Err("missing program file".to_string()),
+            [_, unknown, ..] => Err(format!("unexpected argument '{}'", unknown))
+        },
+        None | Some("help") | Some("--help") | Some("-h") => Ok(Command::Help),
+        Some(unknown) => Err(format!("unknown command '{}'", unknown))
+    }
+}
+
+/// Without a day selection all days are selected.
+fn parse_optional_days (args: &[String]) -> Result<RunOptions, String> {
+    match args.first() {
+        Some(text) if !text.starts_with('-') => parse_run_options(parse_day_selection(text)?, &args[1..]),
+        _ => parse_run_options(DaySelection::All, args)
+    }
+}
+
+fn reject_run_only_options (options: RunOptions) -> Result<RunOptions, String> {
+    if options.jobs.is_some() || options.format != OutputFormat::Text || options.trace.is_some() {
+        Err("--jobs, --format and --trace can only be used with run".to_string())
+    }
+    else {
+        Ok(options)
+    }
+}
+
+/// Takes the bench specific options out of `args` and returns the remaining arguments.
+fn parse_bench_options (args: &[String]) -> Result<(BenchOptions, Vec<String>), String> {
+    fn parse_count (text: &str, minimum: u32) -> Result<u32, String> {
+        match text.parse::<u32>() {
+            Ok(count) if count >= minimum => Ok(count),
+            _ => Err(format!("'{}' is not a number of at least {}", text, minimum))
+        }
+    }
+
+    let mut args = args.iter();
+    let mut options = BenchOptions::default();
+    let mut remaining = Vec::new();
+    while let Some(arg) = args.next() {
+        let mut value = || args
+            .next()
+            .ok_or_else(|| format!("missing value for {}", arg));
+        match arg.as_str() {
+            "--warmup" => options.warmup = parse_count(value()?, 0)?,
+            "--iterations" => options.iterations = parse_count(value()?, 1)?,
+            "--baseline" => options.baseline = Some(PathBuf::from(value()?)),
+            "--save-baseline" => options.save_baseline = Some(PathBuf::from(value()?)),
+            "--threshold" => options.threshold = parse_count(value()?, 0)?,
+            _ => remaining.push(arg.clone())
+        }
+    }
+    Ok((options, remaining))
+}
+
+fn parse_run_options (days: DaySelection, args: &[String]) -> Result<RunOptions, String> {
+    let mut args = args.iter();
+    let mut years = YearSelection::Latest;
+    let mut parts = BOTH_PARTS.to_vec();
+    let mut input = None;
+    let mut jobs = None;
+    let mut format = OutputFormat::Text;
+    let mut timeout = None;
+    let mut log_level = Level::Info;
+    let mut trace_path = None;
+    let mut trace_range = None;
+    while let Some(arg) = args.next() {
+        let mut value = || args
+            .next()
+            .ok_or_else(|| format!("missing value for {}", arg));
+        match arg.as_str() {
+            "--year" | "-y" => years = parse_year_selection(value()?)?,
+            "--part" | "-p" => parts = vec![parse_part(value()?)?],
+            "--input-dir" => {
+                input = set_input_source(input, InputSource::Directory(PathBuf::from(value()?)))?;
+            }
+            "--input" | "-i" => {
+                let source = match value()?.as_str() {
+                    "-" => InputSource::Stdin,
+                    path => InputSource::File(PathBuf::from(path))
+                };
+                input = set_input_source(input, source)?;
+            }
+            "--jobs" | "-j" => {
+                let text = value()?;
+                jobs = match text.parse::<usize>() {
+                    Ok(count) if count > 0 => Some(count),
+                    _ => return Err(format!("'{}' is not a number of jobs", text))
+                };
+            }
+            "--format" | "-f" => format = parse_output_format(value()?)?,
+            "--timeout" | "-t" => {
+                let text = value()?;
+                timeout = match text.parse::<f64>() {
+                    Ok(seconds) if seconds > 0.0 && seconds.is_finite() => Some(Duration::from_secs_f64(seconds)),
+                    _ => return Err(format!("'{}' is not a number of seconds", text))
+                };
+            }
+            "--log" | "-l" => {
+                let text = value()?;
+                log_level = Level::parse(text)
+                    .ok_or_else(|| format!("'{}' is not a log level, expected quiet, info, debug or trace", text))?;
+            }
+            "--trace" => trace_path = Some(PathBuf::from(value()?)),
+            "--trace-range" => trace_range = Some(parse_address_range(value()?)?),
+            unknown => return Err(format!("unknown argument '{}'", unknown))
+        }
+    }
+    let trace = match (trace_path, trace_range) {
+        (Some(path), range) => Some((path, range.unwrap_or(0..=usize::MAX))),
+        (None, Some(_)) => return Err("--trace-range needs --trace".to_string()),
+        (None, None) => None
+    };
+    if trace.is_some() && jobs.is_some() {
+        return Err("--trace can't be used with --jobs, the order of the traced programs would depend on the threads".to_string());
+    }
+    let is_single_day = years != YearSelection::All
+        && matches!(days, DaySelection::Range(from, to) if from == to);
+    if !is_single_day && matches!(input, Some(InputSource::File(_)) | Some(InputSource::Stdin)) {
+        return Err("--input can only be used when running a single day".to_string());
+    }
+    Ok(RunOptions { years, days, parts, input, jobs, format, timeout, log_level, trace })
+}
+
+fn parse_new_day_options (day: u32, args: &[String]) -> Result<NewDayOptions, String> {
+    let mut args = args.iter();
+    let mut options = NewDayOptions { year: None, day, input_dir: None };
+    while let Some(arg) = args.next() {
+        let mut value = || args
+            .next()
+            .ok_or_else(|| format!("missing value for {}", arg));
+        match arg.as_str() {
+            "--year" | "-y" => {
+                let text = value()?;
+                options.year = Some(text.parse::<u32>().map_err(|_| format!("'{}' is not a year", text))?);
+            }
+            "--input-dir" => options.input_dir = Some(PathBuf::from(value()?)),
+            unknown => return Err(format!("unknown argument '{}'", unknown))
+        }
+    }
+    Ok(options)
+}
+
+fn parse_guess_options (day: u32, args: &[String]) -> Result<GuessOptions, String> {
+    let mut args = args.iter();
+    let mut options = GuessOptions { year: None, day, input_dir: None, guess: None };
+    let (mut part, mut answer, mut feedback) = (None, None, None);
+    while let Some(arg) = args.next() {
+        let mut value = || args
+            .next()
+            .ok_or_else(|| format!("missing value for {}", arg));
+        match arg.as_str() {
+            "--year" | "-y" => {
+                let text = value()?;
+                options.year = Some(text.parse::<u32>().map_err(|_| format!("'{}' is not a year", text))?);
+            }
+            "--input-dir" => options.input_dir = Some(PathBuf::from(value()?)),
+            "--part" | "-p" => part = Some(parse_part(value()?)?),
+            "--answer" | "-a" => answer = Some(value()?.trim().to_string()),
+            "--feedback" => {
+                let text = value()?;
+                feedback = Some(Feedback::parse(text)
+                    .ok_or_else(|| format!("'{}' is not a feedback, expected too-high, too-low or wrong", text))?);
+            }
+            unknown => return Err(format!("unknown argument '{}'", unknown))
+        }
+    }
+    options.guess = match (part, answer, feedback) {
+        (Some(part), Some(answer), Some(feedback)) => Some(Guess { part, answer, feedback }),
+        (None, None, None) => None,
+        _ => return Err("--part, --answer and --feedback are all needed to record a guess".to_string())
+    };
+    Ok(options)
+}
+
+fn parse_year_selection (text: &str) -> Result<YearSelection, String> {
+    match text {
+        "all" => Ok(YearSelection::All),
+        _ => text
+            .parse::<u32>()
+            .map(YearSelection::Year)
+            .map_err(|_| format!("'{}' is not a year", text))
+    }
+}
+
+fn parse_day (text: &str) -> Result<u32, String> {
+    match text.parse::<u32>() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!("'{}' is not a day between 1 and 25", text))
+    }
+}
+
+/// An inclusive range of addresses like `100..200`.
+fn parse_address_range (text: &str) -> Result<RangeInclusive<usize>, String> {
+    let error = || format!("'{}' is not an address range like 100..200", text);
+    let (from, to) = text.split_once("..").ok_or_else(error)?;
+    let from = from.parse::<usize>().map_err(|_| error())?;
+    let to = to.trim_start_matches('=').parse::<usize>().map_err(|_| error())?;
+    if from <= to { Ok(from..=to) }
+    else { Err(format!("'{}' is an empty range", text)) }
+}
+
+fn parse_day_selection (text: &str) -> Result<DaySelection, String> {
+    if text == "all" {
+        Ok(DaySelection::All)
+    }
+    else if let Some((from, to)) = text.split_once("..") {
+        let from = parse_day(from)?;
+        let to = parse_day(to.trim_start_matches('='))?;
+        if from <= to { Ok(DaySelection::Range(from, to)) }
+        else { Err(format!("'{}' is an empty range", text)) }
+    }
+    else {
+        parse_day(text).map(|day| DaySelection::Range(day, day))
+    }
+}
+
+fn set_input_source (current: Option<InputSource>, next: InputSource) -> Result<Option<InputSource>, String> {
+    match current {
+        Some(_) => Err("--input and --input-dir may only be given once and not together".to_string()),
+        None => Ok(Some(next))
+    }
+}
+
+fn parse_output_format (text: &str) -> Result<OutputFormat, String> {
+    match text {
+        "text" => Ok(OutputFormat::Text),
+        "json" => Ok(OutputFormat::Json),
+        "csv" => Ok(OutputFormat::Csv),
+        _ => Err(format!("'{}' is not a format, expected text, json or csv", text))
+    }
+}
+
+fn parse_part (text: &str) -> Result<Part, String> {
+    match text {
+        "1" => Ok(Part::First),
+        "2" => Ok(Part::Second),
+        _ => Err(format!("'{}' is not a part, expected 1 or 2", text))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_line (line: &str) -> Result<Command, String> {
+        parse(&line.split_whitespace().map(|arg| arg.to_string()).collect::<Vec<String>>())
+    }
+
+    fn run_options (line: &str) -> RunOptions {
+        match parse_line(line) {
+            Ok(Command::Run(options)) => options,
+            other => panic!("expected run options for '{}' but got {:?}", line, other)
+        }
+    }
+
+    #[test]
+    fn parses_day_selections_and_run_options () {
+        assert_eq!(run_options("run 7").days, DaySelection::Range(7, 7));
+        assert_eq!(run_options("run 3..12").days, DaySelection::Range(3, 12));
+        assert_eq!(run_options("run 3..=12").days, DaySelection::Range(3, 12));
+        assert_eq!(run_options("run all").days, DaySelection::All);
+        let options = run_options("run all --jobs 4 --format csv --part 2 --timeout 1.5");
+        assert_eq!(options.jobs, Some(4));
+        assert_eq!(options.format, OutputFormat::Csv);
+        assert_eq!(options.parts, vec![Part::Second]);
+        assert_eq!(options.timeout, Some(Duration::from_millis(1500)));
+        assert_eq!(run_options("run 9 --trace t.txt --trace-range 10..20").trace, Some((PathBuf::from("t.txt"), 10..=20)));
+    }
+
+    #[test]
+    fn rejects_bad_values () {
+        assert_eq!(parse_line("run"), Err("missing day selection".to_string()));
+        assert_eq!(parse_line("run 26"), Err("'26' is not a day between 1 and 25".to_string()));
+        assert_eq!(parse_line("run 12..3"), Err("'12..3' is an empty range".to_string()));
+        assert_eq!(parse_line("run all --jobs 0"), Err("'0' is not a number of jobs".to_string()));
+        assert_eq!(parse_line("run all --format xml"), Err("'xml' is not a format, expected text, json or csv".to_string()));
+        assert_eq!(parse_line("run all --timeout -1"), Err("'-1' is not a number of seconds".to_string()));
+        assert_eq!(parse_line("run all --part"), Err("missing value for --part".to_string()));
+        assert_eq!(
+            parse_line("run all --jobs 2 --trace t.txt"),
+            Err("--trace can't be used with --jobs, the order of the traced programs would depend on the threads".to_string()));
+        assert_eq!(parse_line("run all --input day.txt"), Err("--input can only be used when running a single day".to_string()));
+    }
+}
@@ -0,0 +1,45 @@
+use std::error::Error;
+use std::fmt;
+use std::time::Duration;
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub enum DayError {
+    /// The input couldn't be parsed, `line` is 1-based.
+    Parse { line: usize, message: String },
+    /// The input was parsed but the solution found no answer for it.
+    Unsolvable(String),
+    /// The IntCode program faulted or behaved unexpectedly.
+    VmFault(String),
+    /// The runner gave up waiting for the parsing or a part.
+    TimedOut(Duration),
+    /// The parsing or a part panicked, with the panic's message.
+    Panicked(String)
+}
+
+impl DayError {
+    pub fn parse<T: fmt::Display> (line: usize, message: T) -> DayError {
+        DayError::Parse { line, message: message.to_string() }
+    }
+
+    pub fn unsolvable<T: fmt::Display> (message: T) -> DayError {
+        DayError::Unsolvable(message.to_string())
+    }
+
+    pub fn vm_fault<T: fmt::Display> (message: T) -> DayError {
+        DayError::VmFault(message.to_string())
+    }
+}
+
+impl fmt::Display for DayError {
+    fn fmt (&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DayError::Parse { line, message } => write!(f, "parse error on line {}: {}", line, message),
+            DayError::Unsolvable(message) => write!(f, "no solution: {}", message),
+            DayError::VmFault(message) => write!(f, "IntCode fault: {}", message),
+            DayError::TimedOut(timeout) => write!(f, "timed out after {:?}", timeout),
+            DayError::Panicked(message) => write!(f, "panicked: {}", message)
+        }
+    }
+}
+
+impl Error for DayError {}
@@ -1,24 +1,229 @@
-use std::fs;
-use std::time::Instant;
+use crate::allocations::{self, AllocationStats};
+use crate::answer::Answer;
+use crate::day_error::DayError;
+use crate::guesses::{self, Journal};
+use crate::input::InputStore;
+use crate::watchdog;
+use std::any::Any;
+use std::io::{self, IsTerminal};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub enum Part {
+    First,
+    Second
+}
+
+pub const BOTH_PARTS: [Part; 2] = [Part::First, Part::Second];
+
+impl Part {
+    pub fn number (&self) -> u32 {
+        match self {
+            Part::First => 1,
+            Part::Second => 2
+        }
+    }
+
+    pub fn name (&self) -> &'static str {
+        match self {
+            Part::First => "First",
+            Part::Second => "Second"
+        }
+    }
+}
+
+/// A puzzle solution. The raw input is parsed once and both parts work on the parsed value.
 pub trait DayTasks {
+    type Input;
+
+    fn year (&self) -> String;
     fn day_number (&self) -> String;
-    fn task_0 (&self, input: &String) -> String;
-    fn task_1 (&self, input: &String) -> String;
-
-    fn run (&self) {
-        let path = format! (".\\Input\\2019\\{}.txt", self.day_number());
-        let contents = fs::read_to_string(path)
-            .expect("Something went wrong reading the input file");
-        println!("Day {}:", self.day_number());
+    fn parse (&self, input: &str) -> Result<Self::Input, DayError>;
+    fn task_0 (&self, input: &Self::Input) -> Result<Answer, DayError>;
+    fn task_1 (&self, input: &Self::Input) -> Result<Answer, DayError>;
+
+    fn task (&self, part: Part, input: &Self::Input) -> Result<Answer, DayError> {
+        match part {
+            Part::First => self.task_0(input),
+            Part::Second => self.task_1(input)
+        }
+    }
+
+    /// Called by the runner when a task returned `Answer::RequiresInteraction` and a user sits at the terminal.
+    fn interact (&self, _input: &Self::Input, _part: Part) -> Result<Answer, DayError> {
+        Ok(Answer::RequiresInteraction)
+    }
+
+    /// Days which usually need a user at the terminal are left out of unattended parallel runs.
+    fn is_interactive (&self) -> bool {
+        false
+    }
+}
+
+pub struct PartRun {
+    pub part: Part,
+    pub result: Result<Answer, DayError>,
+    pub duration: Duration,
+    /// Only counted with the `count-allocations` feature.
+    pub allocations: Option<AllocationStats>
+}
+
+pub struct DayRun {
+    pub parse_duration: Duration,
+    /// The results of the selected parts, or the error if the input couldn't be parsed.
+    pub parts: Result<Vec<PartRun>, DayError>
+}
+
+/// A day's parsed input with its type hidden, only the day that parsed it can solve it.
+pub type ParsedInput = Arc<dyn Any + Send + Sync>;
+
+/// Object safe view of `DayTasks` which hides the parsed input type, so days of any year can share one registry.
+pub trait Solution: Send + Sync {
+    fn year (&self) -> String;
+    fn day_number (&self) -> String;
+    fn is_interactive (&self) -> bool;
+    /// Parses the input and measures how long that took.
+    fn parse_input (&self, contents: &str) -> (Duration, Result<ParsedInput, DayError>);
+    /// Solves one part of an input which `parse_input` of the same day parsed.
+    fn solve_part (&self, input: &ParsedInput, part: Part, interactive: bool) -> PartRun;
+
+    fn solve (&self, contents: &str, parts: &[Part], interactive: bool) -> DayRun {
+        let (parse_duration, parsed) = self.parse_input(contents);
+        let parts = parsed.map(|input| parts.iter().map(|part| self.solve_part(&input, *part, interactive)).collect());
+        DayRun { parse_duration, parts }
+    }
+}
+
+/// Solves a day and prints the results. With a timeout every phase gets that long before it is given up.
+pub fn run (day: &Arc<dyn Solution>, input: &InputStore, parts: &[Part], timeout: Option<Duration>) {
+    let contents = match input.read(&day.year(), &day.day_number()) {
+        Ok(contents) => contents,
+        Err(message) => { println!("{} Day {}: {}", day.year(), day.day_number(), message); return; }
+    };
+    println!("{} Day {}:", day.year(), day.day_number());
+    let journal = load_journal(input, &day.year(), &day.day_number());
+    let day_run = watchdog::solve(day, contents, parts, io::stdin().is_terminal(), timeout);
+    match day_run.parts {
+        Ok(part_runs) => {
+            println!("Parsing took {:?}", day_run.parse_duration);
+            for part_run in part_runs {
+                print_result(&part_run);
+                if let Ok(answer) = &part_run.result {
+                    for warning in journal.check(part_run.part, answer) {
+                        println!("Warning: {}", warning);
+                    }
+                }
+            }
+        }
+        Err(error @ DayError::TimedOut(_)) => println!("Parsing {}", error),
+        Err(error) => println!("Parsing failed with {} (took {:?})", error, day_run.parse_duration)
+    }
+}
+
+/// The guesses for a day, a journal that can't be read only costs the warnings.
+pub fn load_journal (input: &InputStore, year: &str, day_number: &str) -> Journal {
+    match input.journal_path(year, day_number).map(|path| guesses::load(&path)) {
+        Some(Ok(journal)) => journal,
+        Some(Err(message)) => { eprintln!("warning: {}", message); Journal::default() }
+        None => Journal::default()
+    }
+}
+
+impl<T: DayTasks + Send + Sync> Solution for T where T::Input: Send + Sync + 'static {
+    fn year (&self) -> String {
+        DayTasks::year(self)
+    }
+
+    fn day_number (&self) -> String {
+        DayTasks::day_number(self)
+    }
+
+    fn is_interactive (&self) -> bool {
+        DayTasks::is_interactive(self)
+    }
+
+    fn parse_input (&self, contents: &str) -> (Duration, Result<ParsedInput, DayError>) {
         let start = Instant::now();
-        let result = self.task_0(&contents);
-        let finish = Instant::now();
-        println!("First Result = {} (took {:?})", result, finish - start);
+        let parsed = catch_panic(|| self.parse(contents)).map(|input| Arc::new(input) as ParsedInput);
+        (start.elapsed(), parsed)
+    }
+
+    fn solve_part (&self, input: &ParsedInput, part: Part, interactive: bool) -> PartRun {
         let start = Instant::now();
-        let result = self.task_1(&contents);
-        let finish = Instant::now();
-        println!("Second Result = {} (took {:?})", result, finish - start);
-    
+        let (result, allocations) = allocations::measure(|| catch_panic(|| {
+            let input = input
+                .downcast_ref::<T::Input>()
+                .ok_or_else(|| DayError::unsolvable("the input was parsed by another day"))?;
+            let result = self.task(part, input);
+            if interactive && result == Ok(Answer::RequiresInteraction) { self.interact(input, part) } else { result }
+        }));
+        PartRun { part, result, duration: start.elapsed(), allocations }
+    }
+}
+
+/// Turns a panic of `work` into `DayError::Panicked`, so one broken day doesn't abort the whole run.
+fn catch_panic<T> (work: impl FnOnce() -> Result<T, DayError>) -> Result<T, DayError> {
+    panic::catch_unwind(AssertUnwindSafe(work)).unwrap_or_else(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "without a message".to_string());
+        Err(DayError::Panicked(message))
+    })
+}
+
+fn print_result (part_run: &PartRun) {
+    let name = part_run.part.name();
+    let took = match &part_run.allocations {
+        Some(allocations) => format!("took {:?}, {}", part_run.duration, allocations),
+        None => format!("took {:?}", part_run.duration)
+    };
+    match &part_run.result {
+        Ok(answer @ Answer::Image(_)) => {
+            println!("{} Result = ({})", name, took);
+            println!("{}", answer.render());
+        }
+        Ok(answer) => println!("{} Result = {} ({})", name, answer.render(), took),
+        Err(error @ DayError::TimedOut(_)) => println!("{} Result {}", name, error),
+        Err(error) => println!("{} Result failed with {} ({})", name, error, took)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Fragile;
+
+    impl DayTasks for Fragile {
+        type Input = Vec<u32>;
+
+        fn year (&self) -> String {
+            "2019".to_string()
+        }
+        fn day_number (&self) -> String {
+            "00".to_string()
+        }
+        fn parse (&self, input: &str) -> Result<Vec<u32>, DayError> {
+            Ok(input.bytes().map(u32::from).collect())
+        }
+        fn task_0 (&self, input: &Vec<u32>) -> Result<Answer, DayError> {
+            Ok(Answer::from(input[10]))
+        }
+        fn task_1 (&self, input: &Vec<u32>) -> Result<Answer, DayError> {
+            Ok(Answer::from(input.len()))
+        }
+    }
+
+    #[test]
+    fn a_panicking_part_is_reported_and_the_next_part_still_runs () {
+        let part_runs = Fragile.solve("99", &BOTH_PARTS, false).parts.unwrap();
+        assert_eq!(
+            part_runs[0].result,
+            Err(DayError::Panicked("index out of bounds: the len is 2 but the index is 10".to_string())));
+        assert_eq!(part_runs[1].result, Ok(Answer::from(2)));
     }
 }
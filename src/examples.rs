@@ -0,0 +1,28 @@
+use crate::day_tasks::{DayTasks, Part};
+use crate::input;
+
+/// A worked example from the puzzle description: the input, the part and the expected answer.
+///
+/// Where the puzzle's examples change a parameter that the real input hardcodes, like the 10 or 100 steps
+/// Day 12 simulates instead of 1000, the day's tests call the parameterised helper directly instead.
+/// IntCode days have no worked examples, their tests assemble small programs with the assembler.
+pub type Example = (&'static str, Part, &'static str);
+
+/// Solves every example, normalized like a real input, and fails with a list of all examples that didn't give the expected answer.
+pub fn check<T: DayTasks> (day: &T, examples: &[Example]) {
+    let failures = examples
+        .iter()
+        .enumerate()
+        .filter_map(|(index, (input, part, expected))| {
+            let result = day
+                .parse(&input::normalize(input))
+                .and_then(|parsed| day.task(*part, &parsed));
+            match result {
+                Ok(answer) if answer.matches(expected) => None,
+                Ok(answer) => Some(format!("example {} ({} part): expected {} but got {}", index + 1, part.name(), expected, answer.as_line())),
+                Err(error) => Some(format!("example {} ({} part): expected {} but failed with {}", index + 1, part.name(), expected, error))
+            }
+        })
+        .collect::<Vec<String>>();
+    assert!(failures.is_empty(), "{} Day {}:\n{}", day.year(), day.day_number(), failures.join("\n"));
+}
@@ -0,0 +1,93 @@
+use std::env;
+use std::fs;
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+pub const SESSION_VARIABLE: &str = "AOC_SESSION";
+pub const URL_VARIABLE: &str = "AOC_URL";
+pub const DEFAULT_URL: &str = "https://adventofcode.com";
+/// Holds the session cookie when `$AOC_SESSION` isn't set, looked up in the input directory.
+pub const SESSION_FILE: &str = ".session";
+
+/// Gets puzzle inputs which aren't cached yet.
+pub trait Fetcher: Send + Sync {
+    fn fetch (&self, year: &str, day_number: &str) -> Result<String, String>;
+}
+
+/// Downloads inputs from `<base_url>/<year>/day/<day>/input`, authenticated with the session cookie.
+/// Plain `http` is spoken directly, `https` is left to `curl`.
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct HttpFetcher {
+    pub base_url: String,
+    pub session: String
+}
+
+impl Fetcher for HttpFetcher {
+    fn fetch (&self, year: &str, day_number: &str) -> Result<String, String> {
+        let url = format!("{}/{}/day/{}/input", self.base_url.trim_end_matches('/'), year, day_number.trim_start_matches('0'));
+        let body = if url.starts_with("http://") { http_get(&url, &self.session) } else { curl_get(&url, &self.session) };
+        body.map_err(|message| format!("couldn't fetch '{}': {}", url, message))
+    }
+}
+
+/// The fetcher configured by `$AOC_SESSION` or `<input directory>/.session` and `$AOC_URL`,
+/// `None` if there is no session cookie.
+pub fn from_environment (input_directory: &Path) -> Option<HttpFetcher> {
+    let session = env::var(SESSION_VARIABLE)
+        .ok()
+        .or_else(|| fs::read_to_string(input_directory.join(SESSION_FILE)).ok())
+        .map(|session| session.trim().to_string())
+        .filter(|session| !session.is_empty())?;
+    let base_url = env::var(URL_VARIABLE).unwrap_or_else(|_| DEFAULT_URL.to_string());
+    Some(HttpFetcher { base_url, session })
+}
+
+fn http_get (url: &str, session: &str) -> Result<String, String> {
+    let address = url.trim_start_matches("http://");
+    let (host, path) = match address.find('/') {
+        Some(index) => (&address[..index], &address[index..]),
+        None => (address, "/")
+    };
+    let host_and_port = if host.contains(':') { host.to_string() } else { format!("{}:80", host) };
+    let mut stream = TcpStream::connect(&host_and_port).map_err(|error| error.to_string())?;
+    // HTTP/1.0 keeps the response free of chunked encoding
+    write!(stream, "GET {} HTTP/1.0\r\nHost: {}\r\nCookie: session={}\r\nUser-Agent: advent_of_code_rust\r\n\r\n", path, host, session)
+        .map_err(|error| error.to_string())?;
+    let mut response = String::new();
+    stream.read_to_string(&mut response).map_err(|error| error.to_string())?;
+    let (head, body) = response
+        .split_once("\r\n\r\n")
+        .ok_or_else(|| "the response has no body".to_string())?;
+    let status = head.lines().next().unwrap_or_default();
+    match status.split_whitespace().nth(1) {
+        Some("200") => Ok(body.to_string()),
+        _ => Err(format!("the server answered '{}'", status))
+    }
+}
+
+fn curl_get (url: &str, session: &str) -> Result<String, String> {
+    let mut child = Command::new("curl")
+        .args(["--fail", "--silent", "--show-error", "--config", "-", url])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|error| format!("couldn't run curl: {}", error))?;
+    // the cookie is passed on stdin, so it doesn't show up in the process list
+    if let Some(mut stdin) = child.stdin.take() {
+        writeln!(stdin, "header = \"Cookie: session={}\"", session).map_err(|error| error.to_string())?;
+    }
+    let output = child.wait_with_output().map_err(|error| error.to_string())?;
+    if output.status.success() {
+        String::from_utf8(output.stdout).map_err(|_| "the input is not valid UTF-8".to_string())
+    }
+    else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
+
+pub fn normalize_line_endings (text: &str) -> String {
+    text.replace("\r\n", "\n")
+}
@@ -0,0 +1,173 @@
+use crate::answer::Answer;
+use crate::day_tasks::{Part, BOTH_PARTS};
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::Path;
+
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub enum Feedback {
+    TooHigh,
+    TooLow,
+    Wrong
+}
+
+impl Feedback {
+    pub fn parse (text: &str) -> Option<Feedback> {
+        match text {
+            "too-high" => Some(Feedback::TooHigh),
+            "too-low" => Some(Feedback::TooLow),
+            "wrong" => Some(Feedback::Wrong),
+            _ => None
+        }
+    }
+}
+
+impl fmt::Display for Feedback {
+    fn fmt (&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(match self {
+            Feedback::TooHigh => "too-high",
+            Feedback::TooLow => "too-low",
+            Feedback::Wrong => "wrong"
+        })
+    }
+}
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct Guess {
+    pub part: Part,
+    pub answer: String,
+    pub feedback: Feedback
+}
+
+/// The rejected answers of one input, read from a file with one `<part> <feedback>: <answer>` line per guess.
+/// Empty lines and lines starting with `#` are ignored.
+#[derive(Eq, PartialEq, Clone, Debug, Default)]
+pub struct Journal {
+    pub guesses: Vec<Guess>
+}
+
+impl Journal {
+    /// Explains why `answer` can't be right for `part`, judging by the earlier guesses.
+    pub fn check (&self, part: Part, answer: &Answer) -> Vec<String> {
+        let line = answer.as_line();
+        let number = match answer {
+            Answer::Number(number) => Some(*number),
+            _ => None
+        };
+        let mut warnings = Vec::new();
+        for guess in self.guesses.iter().filter(|guess| guess.part == part) {
+            let warning = if guess.answer == line {
+                Some(format!("{} was already guessed and was {}", line, guess.feedback))
+            }
+            else {
+                match (guess.feedback, number, guess.answer.parse::<i128>().ok()) {
+                    (Feedback::TooHigh, Some(number), Some(guessed)) if number > guessed =>
+                        Some(format!("{} is above {} which was too high", number, guessed)),
+                    (Feedback::TooLow, Some(number), Some(guessed)) if number < guessed =>
+                        Some(format!("{} is below {} which was too low", number, guessed)),
+                    _ => None
+                }
+            };
+            warnings.extend(warning);
+        }
+        warnings
+    }
+
+    /// The range the answer of `part` has to be in, as far as the guesses tell.
+    pub fn bounds (&self, part: Part) -> (Option<i128>, Option<i128>) {
+        let bound = |feedback: Feedback| self.guesses
+            .iter()
+            .filter(move |guess| guess.part == part && guess.feedback == feedback)
+            .filter_map(|guess| guess.answer.parse::<i128>().ok());
+        (bound(Feedback::TooLow).max(), bound(Feedback::TooHigh).min())
+    }
+}
+
+pub fn parse (text: &str) -> Result<Journal, String> {
+    let mut guesses = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') { continue; }
+        let expected = || format!("line {}: expected '<part> <too-high|too-low|wrong>: <answer>'", index + 1);
+        let (head, answer) = line.split_once(':').ok_or_else(expected)?;
+        let (part, feedback) = head.trim().split_once(' ').ok_or_else(expected)?;
+        let part = match part {
+            "1" => Part::First,
+            "2" => Part::Second,
+            other => return Err(format!("line {}: '{}' is not a part", index + 1, other))
+        };
+        let feedback = Feedback::parse(feedback.trim())
+            .ok_or_else(|| format!("line {}: '{}' is not a feedback", index + 1, feedback.trim()))?;
+        guesses.push(Guess { part, answer: answer.trim().to_string(), feedback });
+    }
+    Ok(Journal { guesses })
+}
+
+/// A missing journal is not an error, nothing was guessed yet.
+pub fn load (path: &Path) -> Result<Journal, String> {
+    match fs::read_to_string(path) {
+        Ok(text) => parse(&text).map_err(|message| format!("{}: {}", path.display(), message)),
+        Err(error) if error.kind() == ErrorKind::NotFound => Ok(Journal::default()),
+        Err(error) => Err(format!("couldn't read guess journal '{}': {}", path.display(), error))
+    }
+}
+
+pub fn record (path: &Path, guess: &Guess) -> Result<(), String> {
+    let error = |error: std::io::Error| format!("couldn't write guess journal '{}': {}", path.display(), error);
+    if let Some(directory) = path.parent() {
+        fs::create_dir_all(directory).map_err(error)?;
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(path).map_err(error)?;
+    writeln!(file, "{} {}: {}", guess.part.number(), guess.feedback, guess.answer).map_err(error)
+}
+
+pub fn print_journal (journal: &Journal) {
+    for part in BOTH_PARTS.iter() {
+        let guesses = journal.guesses.iter().filter(|guess| guess.part == *part).collect::<Vec<&Guess>>();
+        if guesses.is_empty() { continue; }
+        println!("Part {}:", part.number());
+        for guess in guesses {
+            println!("    {:<10}{}", guess.feedback, guess.answer);
+        }
+        match journal.bounds(*part) {
+            (Some(low), Some(high)) => println!("    the answer is above {} and below {}", low, high),
+            (Some(low), None) => println!("    the answer is above {}", low),
+            (None, Some(high)) => println!("    the answer is below {}", high),
+            (None, None) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const JOURNAL: &str = "\
+# part 1
+1 too-low: 100
+1 too-high: 200
+1 wrong: 150
+2 wrong: ABC
+";
+
+    #[test]
+    fn warns_about_known_wrong_answers_and_bounds () {
+        let journal = parse(JOURNAL).unwrap();
+        assert_eq!(journal.bounds(Part::First), (Some(100), Some(200)));
+        assert_eq!(journal.check(Part::First, &Answer::Number(120)), Vec::<String>::new());
+        assert_eq!(journal.check(Part::First, &Answer::Number(150)), vec!["150 was already guessed and was wrong"]);
+        assert_eq!(journal.check(Part::First, &Answer::Number(99)), vec!["99 is below 100 which was too low"]);
+        assert_eq!(journal.check(Part::First, &Answer::Number(200)), vec!["200 was already guessed and was too-high"]);
+        assert_eq!(journal.check(Part::First, &Answer::Number(250)), vec!["250 is above 200 which was too high"]);
+        assert_eq!(journal.check(Part::Second, &Answer::from("ABC")), vec!["ABC was already guessed and was wrong"]);
+        assert_eq!(journal.check(Part::Second, &Answer::Number(250)), Vec::<String>::new());
+    }
+
+    #[test]
+    fn rejects_malformed_lines () {
+        assert_eq!(parse("1: 100"), Err("line 1: expected '<part> <too-high|too-low|wrong>: <answer>'".to_string()));
+        assert_eq!(parse("3 wrong: 100"), Err("line 1: '3' is not a part".to_string()));
+        assert_eq!(parse("1 close: 100"), Err("line 1: 'close' is not a feedback".to_string()));
+    }
+}
@@ -0,0 +1,272 @@
+use crate::day_error::DayError;
+use crate::fetch::{self, Fetcher};
+use crate::info;
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+pub const INPUT_DIR_VARIABLE: &str = "AOC_INPUT_DIR";
+pub const DEFAULT_INPUT_DIR: &str = "Input";
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub enum InputSource {
+    Directory(PathBuf),
+    File(PathBuf),
+    Stdin
+}
+
+impl InputSource {
+    pub fn path (&self, year: &str, day_number: &str) -> Option<PathBuf> {
+        match self {
+            InputSource::Directory(directory) => Some(directory.join(year).join(format!("{}.txt", day_number))),
+            InputSource::File(path) => Some(path.clone()),
+            InputSource::Stdin => None
+        }
+    }
+
+    /// The known answers belonging to an input are stored next to it as `<input>.answers`.
+    pub fn answers_path (&self, year: &str, day_number: &str) -> Option<PathBuf> {
+        self.path(year, day_number).map(|path| path.with_extension("answers"))
+    }
+
+    /// The answers guessed for an input are journaled next to it as `<input>.guesses`.
+    pub fn journal_path (&self, year: &str, day_number: &str) -> Option<PathBuf> {
+        self.path(year, day_number).map(|path| path.with_extension("guesses"))
+    }
+
+    pub fn read (&self, year: &str, day_number: &str) -> Result<String, String> {
+        match self.path(year, day_number) {
+            Some(path) => fs::read_to_string(&path)
+                .map_err(|error| format!("couldn't read input file '{}': {}", path.display(), error)),
+            None => {
+                let mut contents = String::new();
+                io::stdin()
+                    .read_to_string(&mut contents)
+                    .map(|_| contents)
+                    .map_err(|error| format!("couldn't read input from stdin: {}", error))
+            }
+        }
+    }
+}
+
+/// Reads inputs from their source. Inputs missing from an input directory are fetched, if there is
+/// a fetcher, and cached in the directory.
+pub struct InputStore {
+    source: InputSource,
+    fetcher: Option<Box<dyn Fetcher>>
+}
+
+impl InputStore {
+    pub fn new (source: InputSource, fetcher: Option<Box<dyn Fetcher>>) -> InputStore {
+        InputStore { source, fetcher }
+    }
+
+    /// Input directories get the fetcher configured by the environment, see `fetch::from_environment`.
+    pub fn from_environment (source: InputSource) -> InputStore {
+        let fetcher = match &source {
+            InputSource::Directory(directory) => fetch::from_environment(directory).map(|fetcher| Box::new(fetcher) as Box<dyn Fetcher>),
+            _ => None
+        };
+        InputStore::new(source, fetcher)
+    }
+
+    pub fn answers_path (&self, year: &str, day_number: &str) -> Option<PathBuf> {
+        self.source.answers_path(year, day_number)
+    }
+
+    pub fn journal_path (&self, year: &str, day_number: &str) -> Option<PathBuf> {
+        self.source.journal_path(year, day_number)
+    }
+
+    /// The input as the days get it, see `normalize`.
+    pub fn read (&self, year: &str, day_number: &str) -> Result<String, String> {
+        self.read_raw(year, day_number).map(|contents| normalize(&contents))
+    }
+
+    fn read_raw (&self, year: &str, day_number: &str) -> Result<String, String> {
+        let (path, fetcher) = match (&self.source, &self.fetcher) {
+            (InputSource::Directory(_), Some(fetcher)) => (self.source.path(year, day_number), fetcher),
+            _ => return self.source.read(year, day_number)
+        };
+        match path {
+            Some(path) if !path.exists() => {
+                info!("fetching the input of {} Day {} into '{}'", year, day_number, path.display());
+                let contents = fetch::normalize_line_endings(&fetcher.fetch(year, day_number)?);
+                if let Some(directory) = path.parent() {
+                    fs::create_dir_all(directory)
+                        .map_err(|error| format!("couldn't create input directory '{}': {}", directory.display(), error))?;
+                }
+                fs::write(&path, &contents)
+                    .map_err(|error| format!("couldn't cache input file '{}': {}", path.display(), error))?;
+                Ok(contents)
+            }
+            _ => self.source.read(year, day_number)
+        }
+    }
+}
+
+/// The input root used when no directory is given on the command line:
+/// `$AOC_INPUT_DIR` if set, `./Input` otherwise.
+pub fn default_directory () -> PathBuf {
+    env::var_os(INPUT_DIR_VARIABLE)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_INPUT_DIR))
+}
+
+pub fn default_source () -> InputSource {
+    InputSource::Directory(default_directory())
+}
+
+/// Drops a byte order mark, turns `\r\n` into `\n` and strips the trailing whitespace of every line
+/// and of the whole input, so the days don't depend on the editor or the platform the input went through.
+pub fn normalize (text: &str) -> String {
+    text
+        .trim_start_matches('\u{feff}')
+        .lines()
+        .map(str::trim_end)
+        .collect::<Vec<&str>>()
+        .join("\n")
+        .trim_end()
+        .to_string()
+}
+
+/// The lines of `input` with their 1-based numbers, as `DayError::parse` wants them. Blank lines are skipped.
+pub fn lines (input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim_end()))
+        .filter(|(_, line)| !line.is_empty())
+}
+
+/// The characters of `input` as rows, indexed by `[y][x]`.
+pub fn grid (input: &str) -> Vec<Vec<char>> {
+    input
+        .lines()
+        .map(|line| line.trim_end().chars().collect())
+        .collect()
+}
+
+/// The comma separated integers on the first line of `input`, like an IntCode program, further lines are ignored.
+/// Positions in errors are 1-based.
+pub fn csv_ints<T: FromStr> (input: &str) -> Result<Vec<T>, DayError> {
+    first_line(input)
+        .split(',')
+        .map(str::trim)
+        .enumerate()
+        .map(|(position, text)| text
+            .parse::<T>()
+            .map_err(|_| DayError::parse(1, format!("'{}' at position {} is not an integer", text, position + 1))))
+        .collect()
+}
+
+/// The digits on the first line of `input`, like a signal or an image, further lines are ignored.
+/// Positions in errors are 1-based.
+pub fn digits (input: &str) -> Result<Vec<u32>, DayError> {
+    first_line(input)
+        .chars()
+        .enumerate()
+        .map(|(position, c)| c
+            .to_digit(10)
+            .ok_or_else(|| DayError::parse(1, format!("'{}' at position {} is not a digit", c, position + 1))))
+        .collect()
+}
+
+fn first_line (input: &str) -> &str {
+    input.lines().next().unwrap_or_default().trim()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fetch::HttpFetcher;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::process;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+
+    fn cache_directory (name: &str) -> PathBuf {
+        let directory = env::temp_dir().join(format!("advent_of_code_rust-{}-{}", name, process::id()));
+        let _ = fs::remove_dir_all(&directory);
+        directory
+    }
+
+    /// Answers one request with `body` and hands back the request line and headers.
+    fn serve_once (body: &'static str) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let request = BufReader::new(&stream)
+                .lines()
+                .map(|line| line.unwrap())
+                .take_while(|line| !line.is_empty())
+                .collect::<Vec<String>>();
+            write!(stream, "HTTP/1.0 200 OK\r\nContent-Type: text/plain\r\n\r\n{}", body).unwrap();
+            request
+        });
+        (url, server)
+    }
+
+    #[test]
+    fn fetches_missing_inputs_into_the_cache () {
+        let (base_url, server) = serve_once("1\r\n2\r\n");
+        let directory = cache_directory("fetch");
+        let fetcher = HttpFetcher { base_url, session: "secret".to_string() };
+        let store = InputStore::new(InputSource::Directory(directory.clone()), Some(Box::new(fetcher)));
+
+        assert_eq!(store.read("2019", "01"), Ok("1\n2".to_string()));
+        let request = server.join().unwrap();
+        assert_eq!(request[0], "GET /2019/day/1/input HTTP/1.0");
+        assert!(request.contains(&"Cookie: session=secret".to_string()));
+        assert_eq!(fs::read_to_string(directory.join("2019").join("01.txt")).unwrap(), "1\n2\n");
+        let _ = fs::remove_dir_all(&directory);
+    }
+
+    struct CountingFetcher(Arc<AtomicUsize>);
+
+    impl Fetcher for CountingFetcher {
+        fn fetch (&self, _year: &str, _day_number: &str) -> Result<String, String> {
+            self.0.fetch_add(1, Ordering::SeqCst);
+            Ok("fetched\n".to_string())
+        }
+    }
+
+    #[test]
+    fn cached_inputs_are_not_fetched_again () {
+        let directory = cache_directory("cached");
+        fs::create_dir_all(directory.join("2019")).unwrap();
+        fs::write(directory.join("2019").join("02.txt"), "cached\n").unwrap();
+        let fetches = Arc::new(AtomicUsize::new(0));
+        let store = InputStore::new(InputSource::Directory(directory.clone()), Some(Box::new(CountingFetcher(fetches.clone()))));
+
+        assert_eq!(store.read("2019", "02"), Ok("cached".to_string()));
+        assert_eq!(store.read("2019", "03"), Ok("fetched".to_string()));
+        assert_eq!(store.read("2019", "03"), Ok("fetched".to_string()));
+        assert_eq!(fetches.load(Ordering::SeqCst), 1);
+        let _ = fs::remove_dir_all(&directory);
+    }
+
+    #[test]
+    fn normalizes_line_endings_byte_order_marks_and_trailing_whitespace () {
+        assert_eq!(normalize("\u{feff}1,2,3\r\n"), "1,2,3");
+        assert_eq!(normalize("  #.# \r\n#..\t\n\n\n"), "  #.#\n#..");
+        assert_eq!(normalize(""), "");
+    }
+
+    #[test]
+    fn splits_inputs_into_lines_grids_and_numbers () {
+        assert_eq!(lines("a\n\nb \n").collect::<Vec<(usize, &str)>>(), vec![(1, "a"), (3, "b")]);
+        assert_eq!(grid("#.\n.#\n"), vec![vec!['#', '.'], vec!['.', '#']]);
+        assert_eq!(csv_ints::<i128>("1, -2,3\n"), Ok(vec![1, -2, 3]));
+        assert_eq!(csv_ints::<i128>("1,x"), Err(DayError::parse(1, "'x' at position 2 is not an integer")));
+        assert_eq!(csv_ints::<i128>("1,2\n3,x"), Ok(vec![1, 2]));
+        assert_eq!(digits("0123\n"), Ok(vec![0, 1, 2, 3]));
+        assert_eq!(digits("01\n23"), Ok(vec![0, 1]));
+        assert_eq!(digits("01a"), Err(DayError::parse(1, "'a' at position 3 is not a digit")));
+    }
+}
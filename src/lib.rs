@@ -0,0 +1,26 @@
+//! Advent of Code solutions and the machinery to run, verify and benchmark them.
+//! The IntCode VM and its ASCII wrapper are re-exported at the top, every day is a
+//! [`day_tasks::DayTasks`] in `years::y<year>::days`, and [`years::all`] lists them.
+
+pub mod allocations;
+pub mod answer;
+pub mod answers;
+pub mod batch;
+pub mod bench;
+pub mod cancellation;
+pub mod day_error;
+pub mod day_tasks;
+#[cfg(test)]
+mod examples;
+pub mod fetch;
+pub mod guesses;
+pub mod input;
+pub mod logging;
+pub mod output;
+pub mod parallel;
+pub mod scaffold;
+pub mod verify;
+pub mod watchdog;
+pub mod years;
+
+pub use years::y2019::days::{ascii_code, assembler, debugger, disassembler, int_code, tracer};
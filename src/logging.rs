@@ -0,0 +1,83 @@
+use std::fmt;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// How much the solvers and the runner tell on stderr, next to the answers on stdout.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Debug)]
+pub enum Level {
+    /// Nothing at all.
+    Quiet,
+    /// What the runner does on its own, like fetching inputs.
+    Info,
+    /// Summaries of how a solver got to its answer.
+    Debug,
+    /// Every step a solver takes, this gets long.
+    Trace
+}
+
+pub const LEVELS: [Level; 4] = [Level::Quiet, Level::Info, Level::Debug, Level::Trace];
+
+impl Level {
+    pub fn parse (text: &str) -> Option<Level> {
+        LEVELS.iter().copied().find(|level| level.name() == text)
+    }
+
+    pub fn name (self) -> &'static str {
+        match self {
+            Level::Quiet => "quiet",
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace"
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt (&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+static LEVEL: AtomicU8 = AtomicU8::new(Level::Info as u8);
+
+pub fn set_level (level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn level () -> Level {
+    LEVELS[LEVEL.load(Ordering::Relaxed) as usize]
+}
+
+/// Whether records of `level` are written, solvers can check it before collecting expensive diagnostics.
+pub fn enabled (level: Level) -> bool {
+    level != Level::Quiet && level <= self::level()
+}
+
+/// Writes a record as `[<level> <module>] <message>` to stderr, if its level is enabled.
+/// Use the `info!`, `debug!` and `trace!` macros instead of calling this directly.
+pub fn log (level: Level, module_path: &str, message: fmt::Arguments) {
+    if enabled(level) {
+        let module = module_path.rsplit("::").next().unwrap_or(module_path);
+        eprintln!("[{} {}] {}", level, module, message);
+    }
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($argument:tt)*) => {
+        $crate::logging::log($crate::logging::Level::Info, module_path!(), format_args!($($argument)*))
+    };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($argument:tt)*) => {
+        $crate::logging::log($crate::logging::Level::Debug, module_path!(), format_args!($($argument)*))
+    };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($argument:tt)*) => {
+        $crate::logging::log($crate::logging::Level::Trace, module_path!(), format_args!($($argument)*))
+    };
+}
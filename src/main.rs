@@ -1,40 +1,207 @@
-mod days;
-mod day_tasks;
-use day_tasks::DayTasks;
+mod cli;
+use advent_of_code_rust::{assembler, batch, bench, day_tasks, debugger, disassembler, guesses, input, int_code, logging, output, parallel, scaffold, tracer, verify, years};
+use advent_of_code_rust::day_tasks::Solution;
+use advent_of_code_rust::input::{InputSource, InputStore};
+use cli::{Command, OutputFormat, RunOptions};
+use std::env;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::process;
+use std::sync::Arc;
 use std::time::Instant;
 
 fn main() {
-    let start = Instant::now();
-    let finish = Instant::now();
-    let diff = finish - start;
-    println!("My machine does \"nothing\" as fast as {:?}!", diff);
-    let start = Instant::now();
-    //days::day_01::Day01.run();
-    //days::day_02::Day02.run();
-    //days::day_03::Day03.run();
-    //days::day_04::Day04.run();
-    //days::day_05::Day05.run();
-    //days::day_06::Day06.run();
-    //days::day_07::Day07.run();
-    //days::day_08::Day08.run();
-    //days::day_09::Day09.run();
-    //days::day_10::Day10.run();
-    //days::day_11::Day11.run();
-    //days::day_12::Day12.run();
-    //days::day_13::Day13.run();
-    //days::day_14::Day14.run();
-    //days::day_15::Day15.run();
-    //days::day_16::Day16.run();
-    //days::day_17::Day17.run();
-    //days::day_18::Day18.run();
-    //days::day_19::Day19.run();
-    //days::day_20::Day20.run();
-    //days::day_21::Day21.run();
-    days::day_22::Day22.run();
-    //days::day_23::Day23.run();
-    //days::day_24::Day24.run();
-    //days::day_25::Day25.run();
-    let finish = Instant::now();
-    let diff = finish - start;
-    println!("The whole run took in total {:?}!", diff);
+    let args: Vec<String> = env::args().skip(1).collect();
+    let command = cli::parse(&args);
+    if let Ok(Command::Run(options) | Command::Verify(options) | Command::Bench(options, _) | Command::Batch(options, _)) = &command {
+        logging::set_level(options.log_level);
+    }
+    if let Ok(Command::Run(RunOptions { trace: Some((path, addresses)), .. })) = &command {
+        match tracer::Tracer::to_file(path, addresses.clone()) {
+            Ok(tracer) => tracer::trace_all(Some(tracer)),
+            Err(message) => { eprintln!("error: {}", message); process::exit(2); }
+        }
+    }
+    match command {
+        Ok(Command::Run(options)) if options.format != OutputFormat::Text => {
+            let (selected_days, input) = select(&options);
+            let selected_days = match options.jobs {
+                Some(_) => without_interactive_days(selected_days),
+                None => selected_days
+            };
+            let reports = parallel::run(&selected_days, &input, &options.parts, options.jobs.unwrap_or(1), options.timeout);
+            let records = output::records(&reports, &options.parts);
+            if options.format == OutputFormat::Json { output::print_json(&records) } else { output::print_csv(&records) }
+        }
+        Ok(Command::Run(options)) => {
+            let (selected_days, input) = select(&options);
+            let start = Instant::now();
+            let finish = Instant::now();
+            let diff = finish - start;
+            println!("My machine does \"nothing\" as fast as {:?}!", diff);
+            let start = Instant::now();
+            match options.jobs {
+                Some(jobs) => {
+                    let unattended_days = without_interactive_days(selected_days);
+                    let reports = parallel::run(&unattended_days, &input, &options.parts, jobs, options.timeout);
+                    parallel::print_report(&reports, &input);
+                }
+                None => {
+                    for day in &selected_days {
+                        day_tasks::run(day, &input, &options.parts, options.timeout);
+                    }
+                }
+            }
+            let finish = Instant::now();
+            let diff = finish - start;
+            println!("The whole run took in total {:?}!", diff);
+        }
+        Ok(Command::Verify(options)) => {
+            let (selected_days, input) = select(&options);
+            let verifications = verify::verify(&selected_days, &input, &options.parts, options.timeout);
+            verify::print_report(&verifications);
+            if verifications.iter().any(|verification| verification.is_regression()) {
+                process::exit(1);
+            }
+        }
+        Ok(Command::Bench(options, bench_options)) => {
+            let (selected_days, input) = select(&options);
+            let baseline = match bench_options.baseline.as_ref().map(|path| bench::load_baseline(path)) {
+                Some(Ok(baseline)) => Some(baseline),
+                Some(Err(message)) => { eprintln!("error: {}", message); process::exit(2); }
+                None => None
+            };
+            let mut benchmarks = bench::bench(&selected_days, &input, &options.parts, &bench_options);
+            if let Some(baseline) = &baseline {
+                bench::apply_baseline(&mut benchmarks, baseline);
+            }
+            bench::print_report(&benchmarks, bench_options.threshold);
+            if let Some(path) = &bench_options.save_baseline {
+                if let Err(message) = bench::save_baseline(path, &benchmarks) {
+                    eprintln!("error: {}", message);
+                    process::exit(2);
+                }
+            }
+            if benchmarks.iter().any(|benchmark| benchmark.is_regression(bench_options.threshold)) {
+                process::exit(1);
+            }
+        }
+        Ok(Command::Batch(options, directory)) => {
+            let (selected_days, _) = select(&options);
+            let files = match batch::input_files(&directory) {
+                Ok(files) if !files.is_empty() => files,
+                Ok(_) => { eprintln!("error: there are no *.txt inputs in '{}'", directory.display()); process::exit(2); }
+                Err(message) => { eprintln!("error: {}", message); process::exit(2); }
+            };
+            let mut regression = false;
+            for day in &selected_days {
+                println!("{} Day {}:", day.year(), day.day_number());
+                let rows = batch::run(day, &files, &options.parts, options.timeout);
+                batch::print_report(&rows, &options.parts);
+                regression |= rows.iter().any(|row| row.is_regression());
+            }
+            if regression {
+                process::exit(1);
+            }
+        }
+        Ok(Command::NewDay(options)) => {
+            let year = options.year.or_else(years::latest_year).unwrap_or_default();
+            let input_dir = options.input_dir.unwrap_or_else(input::default_directory);
+            match scaffold::new_day(&scaffold::years_directory(), &input_dir, year, options.day) {
+                Ok(created) => {
+                    for path in created {
+                        println!("Created {}", path.display());
+                    }
+                }
+                Err(message) => {
+                    eprintln!("error: {}", message);
+                    process::exit(2);
+                }
+            }
+        }
+        Ok(Command::Guess(options)) => {
+            let year = options.year.or_else(years::latest_year).unwrap_or_default().to_string();
+            let day_number = format!("{:02}", options.day);
+            let source = InputSource::Directory(options.input_dir.unwrap_or_else(input::default_directory));
+            let path = match source.journal_path(&year, &day_number) {
+                Some(path) => path,
+                None => { eprintln!("error: no journal for {} Day {}", year, day_number); process::exit(2); }
+            };
+            let result = match &options.guess {
+                Some(guess) => guesses::record(&path, guess)
+                    .map(|_| println!("Recorded {} as {} for part {} of {} Day {}", guess.answer, guess.feedback, guess.part.number(), year, day_number)),
+                None => guesses::load(&path).map(|journal| guesses::print_journal(&journal))
+            };
+            if let Err(message) = result {
+                eprintln!("error: {}", message);
+                process::exit(2);
+            }
+        }
+        Ok(Command::Assemble(path)) => {
+            let text_code = fs::read_to_string(&path)
+                .map_err(|error| format!("couldn't read '{}': {}", path.display(), error))
+                .and_then(|source| assembler::assemble(&source).map_err(|message| format!("{}: {}", path.display(), message)));
+            match text_code {
+                Ok(text_code) => println!("{}", text_code),
+                Err(message) => {
+                    eprintln!("error: {}", message);
+                    process::exit(2);
+                }
+            }
+        }
+        Ok(Command::Disassemble(path, source)) => {
+            let listing = disassembler::disassemble(read_program(&path).memory());
+            if source { print!("{}", listing.to_source()) } else { print!("{}", listing) }
+        }
+        Ok(Command::Debug(path)) => {
+            let mut debugger = debugger::Debugger::new(read_program(&path));
+            let stdin = io::stdin();
+            if let Err(error) = debugger::repl(&mut debugger, stdin.lock(), io::stdout()) {
+                eprintln!("error: {}", error);
+                process::exit(2);
+            }
+        }
+        Ok(Command::Help) => println!("{}", cli::USAGE),
+        Err(message) => {
+            eprintln!("error: {}", message);
+            eprintln!("{}", cli::USAGE);
+            process::exit(2);
+        }
+    }
+    if let Err(message) = tracer::flush_all() {
+        eprintln!("error: {}", message);
+        process::exit(2);
+    }
+}
+
+fn read_program (path: &Path) -> int_code::IntCodeProgram {
+    let program = fs::read_to_string(path)
+        .map_err(|error| format!("couldn't read '{}': {}", path.display(), error))
+        .and_then(|text| int_code::create_program(&input::normalize(&text)).map_err(|error| error.to_string()));
+    program.unwrap_or_else(|message| {
+        eprintln!("error: {}", message);
+        process::exit(2);
+    })
+}
+
+/// Unattended runs leave out days that need a user, the skipped days are reported on stderr.
+fn without_interactive_days (days: Vec<Arc<dyn Solution>>) -> Vec<Arc<dyn Solution>> {
+    let (interactive_days, unattended_days): (Vec<_>, Vec<_>) = days
+        .into_iter()
+        .partition(|day| day.is_interactive());
+    for day in interactive_days {
+        eprintln!("Skipping {} Day {}, it needs interaction", day.year(), day.day_number());
+    }
+    unattended_days
+}
+
+fn select (options: &RunOptions) -> (Vec<Arc<dyn Solution>>, InputStore) {
+    let selected_days = years::select(&options.years, &options.days);
+    if selected_days.is_empty() {
+        eprintln!("error: no solutions match the selected years and days");
+        process::exit(2);
+    }
+    let input = InputStore::from_environment(options.input.clone().unwrap_or_else(input::default_source));
+    (selected_days, input)
 }
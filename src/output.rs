@@ -0,0 +1,137 @@
+use crate::answer::Answer;
+use crate::day_error::DayError;
+use crate::day_tasks::Part;
+use crate::parallel::DayReport;
+
+/// One line of machine readable output, there is one record per day and part.
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct Record {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub answer: Option<String>,
+    pub duration_ns: Option<u128>,
+    pub status: &'static str,
+    pub error: Option<String>
+}
+
+pub fn records (reports: &[DayReport], parts: &[Part]) -> Vec<Record> {
+    let mut records = Vec::new();
+    for report in reports {
+        let record = |part: u32, answer: Option<String>, duration_ns: Option<u128>, status: &'static str, error: Option<String>| Record {
+            year: report.year.parse().unwrap_or_default(),
+            day: report.day_number.parse().unwrap_or_default(),
+            part,
+            answer,
+            duration_ns,
+            status,
+            error
+        };
+        match &report.run {
+            Err(message) => records.extend(parts.iter().map(|part| record(part.number(), None, None, "no input", Some(message.clone())))),
+            Ok(day_run) => match &day_run.parts {
+                Err(error) => {
+                    let status = match error {
+                        DayError::TimedOut(_) => "timed out",
+                        DayError::Panicked(_) => "panicked",
+                        _ => "parse error"
+                    };
+                    records.extend(parts.iter().map(|part| record(part.number(), None, None, status, Some(error.to_string()))))
+                }
+                Ok(part_runs) => records.extend(part_runs.iter().map(|part_run| {
+                    let duration_ns = Some(part_run.duration.as_nanos());
+                    match &part_run.result {
+                        Ok(Answer::RequiresInteraction) => record(part_run.part.number(), None, duration_ns, "interactive", None),
+                        Ok(answer) => record(part_run.part.number(), Some(answer.as_line()), duration_ns, "ok", None),
+                        Err(error @ DayError::TimedOut(_)) => record(part_run.part.number(), None, duration_ns, "timed out", Some(error.to_string())),
+                        Err(error @ DayError::Panicked(_)) => record(part_run.part.number(), None, duration_ns, "panicked", Some(error.to_string())),
+                        Err(error @ DayError::VmFault(_)) => record(part_run.part.number(), None, duration_ns, "faulted", Some(error.to_string())),
+                        Err(error) => record(part_run.part.number(), None, duration_ns, "error", Some(error.to_string()))
+                    }
+                }))
+            }
+        }
+    }
+    records
+}
+
+pub fn print_json (records: &[Record]) {
+    fn string_or_null (value: &Option<String>) -> String {
+        value.as_ref().map(|value| json_string(value)).unwrap_or_else(|| "null".to_string())
+    }
+
+    println!("[");
+    for (index, record) in records.iter().enumerate() {
+        println!("  {{\"year\": {}, \"day\": {}, \"part\": {}, \"answer\": {}, \"duration_ns\": {}, \"status\": {}, \"error\": {}}}{}",
+            record.year,
+            record.day,
+            record.part,
+            string_or_null(&record.answer),
+            record.duration_ns.map(|duration| duration.to_string()).unwrap_or_else(|| "null".to_string()),
+            json_string(record.status),
+            string_or_null(&record.error),
+            if index + 1 < records.len() { "," } else { "" });
+    }
+    println!("]");
+}
+
+pub fn print_csv (records: &[Record]) {
+    println!("year,day,part,answer,duration_ns,status,error");
+    for record in records {
+        println!("{},{},{},{},{},{},{}",
+            record.year,
+            record.day,
+            record.part,
+            csv_field(record.answer.as_deref().unwrap_or_default()),
+            record.duration_ns.map(|duration| duration.to_string()).unwrap_or_default(),
+            csv_field(record.status),
+            csv_field(record.error.as_deref().unwrap_or_default()));
+    }
+}
+
+fn json_string (text: &str) -> String {
+    let mut escaped = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c)
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+/// Fields containing separators, quotes or line breaks are quoted as described in RFC 4180.
+fn csv_field (text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    }
+    else {
+        text.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_json_strings () {
+        assert_eq!(json_string("1234"), "\"1234\"");
+        assert_eq!(json_string("say \"hi\"\\n"), "\"say \\\"hi\\\"\\\\n\"");
+        assert_eq!(json_string("a\nb\tc\r\u{1}"), "\"a\\nb\\tc\\r\\u0001\"");
+    }
+
+    #[test]
+    fn quotes_csv_fields () {
+        assert_eq!(csv_field("##../.#.."), "##../.#..");
+        assert_eq!(csv_field("1,2"), "\"1,2\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field("line\nbreak"), "\"line\nbreak\"");
+    }
+}
@@ -0,0 +1,78 @@
+use crate::day_tasks::{self, DayRun, Part, Solution};
+use crate::input::InputStore;
+use crate::watchdog;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+/// The outcome of one day, or why its input couldn't be read.
+pub struct DayReport {
+    pub year: String,
+    pub day_number: String,
+    pub run: Result<DayRun, String>
+}
+
+/// Runs the days on `jobs` worker threads. The reports are returned in the order of `days`.
+pub fn run (days: &[Arc<dyn Solution>], input: &InputStore, parts: &[Part], jobs: usize, timeout: Option<Duration>) -> Vec<DayReport> {
+    let next_day = AtomicUsize::new(0);
+    let reports: Mutex<Vec<Option<DayReport>>> = Mutex::new(days.iter().map(|_| None).collect());
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(days.len()) {
+            scope.spawn(|| {
+                loop {
+                    let index = next_day.fetch_add(1, Ordering::Relaxed);
+                    let day = match days.get(index) {
+                        Some(day) => day,
+                        None => break
+                    };
+                    let (year, day_number) = (day.year(), day.day_number());
+                    let run = input
+                        .read(&year, &day_number)
+                        .map(|contents| watchdog::solve(day, contents, parts, false, timeout));
+                    let report = DayReport { year, day_number, run };
+                    reports.lock().unwrap_or_else(|poisoned| poisoned.into_inner())[index] = Some(report);
+                }
+            });
+        }
+    });
+    reports
+        .into_inner()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+/// Answers which the guess journal rules out are followed by a warning row.
+pub fn print_report (reports: &[DayReport], input: &InputStore) {
+    println!("{:<6}{:<5}{:<7}{:>14}  Result", "Year", "Day", "Phase", "Time");
+    for report in reports {
+        let row = |phase: &str, time: String, result: String|
+            println!("{:<6}{:<5}{:<7}{:>14}  {}", report.year, report.day_number, phase, time, result);
+        let day_run = match &report.run {
+            Ok(day_run) => day_run,
+            Err(message) => { row("input", String::new(), message.clone()); continue; }
+        };
+        let parse_time = format!("{:.2?}", day_run.parse_duration);
+        match &day_run.parts {
+            Ok(part_runs) => {
+                row("parse", parse_time, String::new());
+                let journal = day_tasks::load_journal(input, &report.year, &report.day_number);
+                for part_run in part_runs {
+                    let result = match &part_run.result {
+                        Ok(answer) => answer.as_line(),
+                        Err(error) => format!("failed with {}", error)
+                    };
+                    row(&part_run.part.number().to_string(), format!("{:.2?}", part_run.duration), result);
+                    if let Ok(answer) = &part_run.result {
+                        for warning in journal.check(part_run.part, answer) {
+                            row("", String::new(), format!("warning: {}", warning));
+                        }
+                    }
+                }
+            }
+            Err(error) => row("parse", parse_time, format!("failed with {}", error))
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The `src/years` directory of this crate, new days are generated into it.
+pub fn years_directory () -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("src").join("years")
+}
+
+/// Generates `y<year>/days/day_<day>.rs`, registers it in the year's `days/mod.rs` and creates an empty
+/// input file. A year without solutions yet gets its modules generated and registered in `years/mod.rs`.
+/// Returns the created files.
+pub fn new_day (years_directory: &Path, input_directory: &Path, year: u32, day: u32) -> Result<Vec<PathBuf>, String> {
+    let day_number = format!("{:02}", day);
+    let days_directory = years_directory.join(format!("y{}", year)).join("days");
+    let day_path = days_directory.join(format!("day_{}.rs", day_number));
+    if day_path.exists() {
+        return Err(format!("'{}' already exists", day_path.display()));
+    }
+
+    let mut created = Vec::new();
+    let days_mod_path = days_directory.join("mod.rs");
+    if days_directory.exists() {
+        let days_mod = read(&days_mod_path)?;
+        let days_mod = insert_sorted(&days_mod, "pub mod day_", &format!("pub mod day_{};", day_number))
+            .and_then(|text| insert_sorted(&text, "Arc::new(day_", &format!("Arc::new(day_{}::Day{}),", day_number, day_number)))
+            .ok_or_else(|| format!("couldn't find where to register the day in '{}'", days_mod_path.display()))?;
+        write(&days_mod_path, &days_mod)?;
+    }
+    else {
+        let years_mod_path = years_directory.join("mod.rs");
+        let years_mod = read(&years_mod_path)?;
+        let years_mod = insert_sorted(&years_mod, "pub mod y", &format!("pub mod y{};", year))
+            .and_then(|text| insert_sorted(&text, "days.extend(y", &format!("days.extend(y{}::days::all());", year)))
+            .ok_or_else(|| format!("couldn't find where to register the year in '{}'", years_mod_path.display()))?;
+        fs::create_dir_all(&days_directory)
+            .map_err(|error| format!("couldn't create '{}': {}", days_directory.display(), error))?;
+        let year_mod_path = years_directory.join(format!("y{}", year)).join("mod.rs");
+        write(&year_mod_path, "pub mod days;\n")?;
+        write(&days_mod_path, &days_mod_template(&day_number))?;
+        write(&years_mod_path, &years_mod)?;
+        created.push(year_mod_path);
+        created.push(days_mod_path);
+    }
+    write(&day_path, &day_template(year, &day_number))?;
+    created.push(day_path);
+
+    let input_path = input_directory.join(year.to_string()).join(format!("{}.txt", day_number));
+    if !input_path.exists() {
+        if let Some(parent) = input_path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|error| format!("couldn't create '{}': {}", parent.display(), error))?;
+        }
+        write(&input_path, "")?;
+        created.push(input_path);
+    }
+    Ok(created)
+}
+
+/// Inserts `line` among the lines starting with `prefix`, keeping them sorted and indented alike.
+/// Returns `None` if there is no line starting with `prefix`.
+fn insert_sorted (text: &str, prefix: &str, line: &str) -> Option<String> {
+    let mut lines = text.lines().map(|line| line.to_string()).collect::<Vec<String>>();
+    let matching = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, existing)| existing.trim_start().starts_with(prefix))
+        .map(|(index, _)| index)
+        .collect::<Vec<usize>>();
+    let last = *matching.last()?;
+    let index = matching
+        .iter()
+        .copied()
+        .find(|index| lines[*index].trim_start() > line)
+        .unwrap_or(last + 1);
+    let reference = &lines[matching[0]];
+    let indentation = &reference[..reference.len() - reference.trim_start().len()];
+    lines.insert(index, format!("{}{}", indentation, line));
+    let mut result = lines.join("\n");
+    if text.ends_with('\n') {
+        result.push('\n');
+    }
+    Some(result)
+}
+
+fn read (path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|error| format!("couldn't read '{}': {}", path.display(), error))
+}
+
+fn write (path: &Path, contents: &str) -> Result<(), String> {
+    fs::write(path, contents).map_err(|error| format!("couldn't write '{}': {}", path.display(), error))
+}
+
+fn days_mod_template (day_number: &str) -> String {
+    format!("\
+use crate::day_tasks::Solution;
+use std::sync::Arc;
+
+pub mod day_{day_number};
+
+pub fn all () -> Vec<Arc<dyn Solution>> {{
+    vec![
+        Arc::new(day_{day_number}::Day{day_number}),
+    ]
+}}
+", day_number = day_number)
+}
+
+fn day_template (year: u32, day_number: &str) -> String {
+    format!("\
+use crate::answer::Answer;
+use crate::day_error::DayError;
+use crate::day_tasks;
+
+pub struct Day{day_number};
+
+impl day_tasks::DayTasks for Day{day_number} {{
+    type Input = Vec<String>;
+
+    fn year (&self) -> String {{
+        \"{year}\".to_string()
+    }}
+    fn day_number (&self) -> String {{
+        \"{day_number}\".to_string()
+    }}
+    fn parse (&self, input: &str) -> Result<Vec<String>, DayError> {{
+        Ok(input.lines().map(|line| line.to_string()).collect())
+    }}
+    fn task_0 (&self, _lines: &Vec<String>) -> Result<Answer, DayError> {{
+        Err(DayError::unsolvable(\"not solved yet\"))
+    }}
+    fn task_1 (&self, _lines: &Vec<String>) -> Result<Answer, DayError> {{
+        Err(DayError::unsolvable(\"not solved yet\"))
+    }}
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+    use crate::examples::{{self, Example}};
+
+    const EXAMPLES: &[Example] = &[
+        // (\"<input>\", day_tasks::Part::First, \"<answer>\"),
+    ];
+
+    #[test]
+    fn worked_examples () {{
+        examples::check(&Day{day_number}, EXAMPLES);
+    }}
+}}
+", year = year, day_number = day_number)
+}
@@ -0,0 +1,119 @@
+use crate::answer::Answer;
+use crate::answers;
+use crate::day_tasks::{Part, Solution};
+use crate::input::InputStore;
+use crate::watchdog;
+use std::sync::Arc;
+use std::time::Duration;
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub enum Status {
+    Pass,
+    Fail { expected: String },
+    Missing,
+    Interactive,
+    NoInput(String),
+    Error(String)
+}
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct Verification {
+    pub year: String,
+    pub day_number: String,
+    pub part: Part,
+    pub answer: Option<Answer>,
+    pub status: Status
+}
+
+impl Verification {
+    pub fn is_regression (&self) -> bool {
+        matches!(self.status, Status::Fail { .. } | Status::Error(_))
+    }
+}
+
+pub fn verify (days: &[Arc<dyn Solution>], input: &InputStore, parts: &[Part], timeout: Option<Duration>) -> Vec<Verification> {
+    let mut verifications = Vec::new();
+    for day in days {
+        let (year, day_number) = (day.year(), day.day_number());
+        let verification = |part: Part, answer: Option<Answer>, status: Status| Verification {
+            year: year.clone(),
+            day_number: day_number.clone(),
+            part,
+            answer,
+            status
+        };
+        let contents = match input.read(&year, &day_number) {
+            Ok(contents) => contents,
+            Err(message) => {
+                verifications.extend(parts.iter().map(|part| verification(*part, None, Status::NoInput(message.clone()))));
+                continue;
+            }
+        };
+        let known_answers = match input.answers_path(&year, &day_number).map(|path| answers::load(&path)) {
+            Some(Ok(known_answers)) => known_answers,
+            None => Default::default(),
+            Some(Err(message)) => {
+                verifications.extend(parts.iter().map(|part| verification(*part, None, Status::Error(message.clone()))));
+                continue;
+            }
+        };
+        let part_runs = match watchdog::solve(day, contents, parts, false, timeout).parts {
+            Ok(part_runs) => part_runs,
+            Err(error) => {
+                verifications.extend(parts.iter().map(|part| verification(*part, None, Status::Error(error.to_string()))));
+                continue;
+            }
+        };
+        for part_run in part_runs {
+            let part = part_run.part;
+            let answer = match part_run.result {
+                Ok(answer) => answer,
+                Err(error) => {
+                    verifications.push(verification(part, None, Status::Error(error.to_string())));
+                    continue;
+                }
+            };
+            let status = judge(&answer, known_answers.get(part));
+            verifications.push(verification(part, Some(answer), status));
+        }
+    }
+    verifications
+}
+
+/// Compares an answer with the known one, if there is any.
+pub fn judge (answer: &Answer, expected: Option<&str>) -> Status {
+    match expected {
+        _ if *answer == Answer::RequiresInteraction => Status::Interactive,
+        Some(expected) if answer.matches(expected) => Status::Pass,
+        Some(expected) => Status::Fail { expected: expected.to_string() },
+        None => Status::Missing
+    }
+}
+
+pub fn print_report (verifications: &[Verification]) {
+    println!("{:<6}{:<5}{:<6}{:<10}Answer", "Year", "Day", "Part", "Status");
+    for verification in verifications {
+        let (status, details) = match &verification.status {
+            Status::Pass => ("pass", String::new()),
+            Status::Fail { expected } => ("FAIL", format!(" (expected {})", expected)),
+            Status::Missing => ("missing", " (no known answer)".to_string()),
+            Status::Interactive => ("skipped", String::new()),
+            Status::NoInput(message) => ("no input", message.clone()),
+            Status::Error(message) => ("ERROR", message.clone())
+        };
+        println!("{:<6}{:<5}{:<6}{:<10}{}{}",
+            verification.year,
+            verification.day_number,
+            verification.part.number(),
+            status,
+            verification.answer.as_ref().map(|answer| answer.as_line()).unwrap_or_default(),
+            details);
+    }
+    let count = |predicate: &dyn Fn(&Status) -> bool| verifications.iter().filter(|v| predicate(&v.status)).count();
+    println!("{} passed, {} failed, {} missing, {} skipped, {} without input",
+        count(&|status| *status == Status::Pass),
+        count(&|status| matches!(status, Status::Fail { .. } | Status::Error(_))),
+        count(&|status| *status == Status::Missing),
+        count(&|status| *status == Status::Interactive),
+        count(&|status| matches!(status, Status::NoInput(_))));
+}
@@ -0,0 +1,99 @@
+use crate::cancellation::{self, Cancellation};
+use crate::day_error::DayError;
+use crate::day_tasks::{DayRun, Part, PartRun, Solution};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Solves `day` on worker threads and waits at most `timeout` for the parsing and for each part.
+/// The input is parsed once, every part then gets a worker of its own, so a part that timed out doesn't
+/// hold up the next one. Without a timeout, or when a user answers the day's questions, the day is solved
+/// on the calling thread.
+pub fn solve (day: &Arc<dyn Solution>, contents: String, parts: &[Part], interactive: bool, timeout: Option<Duration>) -> DayRun {
+    let timeout = match timeout {
+        Some(timeout) if !(interactive && day.is_interactive()) => timeout,
+        _ => return day.solve(&contents, parts, interactive)
+    };
+    let parsing_day = Arc::clone(day);
+    let (parse_duration, input) = match on_worker(timeout, move || parsing_day.parse_input(&contents)) {
+        Ok((parse_duration, Ok(input))) => (parse_duration, input),
+        Ok((parse_duration, Err(error))) | Err((error, parse_duration)) => return DayRun { parse_duration, parts: Err(error) }
+    };
+    let part_runs = parts
+        .iter()
+        .map(|part| {
+            let (day, input, part) = (Arc::clone(day), Arc::clone(&input), *part);
+            on_worker(timeout, move || day.solve_part(&input, part, interactive))
+                .unwrap_or_else(|(error, duration)| PartRun { part, result: Err(error), duration, allocations: None })
+        })
+        .collect();
+    DayRun { parse_duration, parts: Ok(part_runs) }
+}
+
+/// Runs `work` on a thread of its own and waits at most `timeout` for it, the error comes with the time waited.
+/// Work that timed out is cancelled, see `Cancellation`, and left behind. Code that doesn't check for the
+/// cancellation keeps its worker busy until it finishes on its own.
+fn on_worker<T: Send + 'static> (timeout: Duration, work: impl FnOnce() -> T + Send + 'static) -> Result<T, (DayError, Duration)> {
+    let (sender, receiver) = mpsc::channel();
+    let cancellation = Cancellation::default();
+    let worker_cancellation = cancellation.clone();
+    let start = Instant::now();
+    thread::spawn(move || {
+        cancellation::install(worker_cancellation);
+        // the receiver is gone once the runner gave up on this worker
+        let _ = sender.send(work());
+    });
+    receiver.recv_timeout(timeout).map_err(|error| {
+        let error = match error {
+            RecvTimeoutError::Timeout => {
+                cancellation.cancel();
+                DayError::TimedOut(timeout)
+            }
+            // panics of the solution are caught on the worker, this is one of the runner itself
+            RecvTimeoutError::Disconnected => DayError::Panicked("the worker stopped without reporting".to_string())
+        };
+        (error, start.elapsed())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answer::Answer;
+    use crate::day_tasks::BOTH_PARTS;
+    use crate::int_code::{self, FaultReason, IntCodeProgramStatus};
+    use crate::years::y2019::days::day_01::Day01;
+    use crate::years::y2019::days::day_09::Day09;
+
+    const TIMEOUT: Duration = Duration::from_millis(100);
+
+    #[test]
+    fn a_looping_day_times_out_and_the_next_day_still_runs () {
+        let looping: Arc<dyn Solution> = Arc::new(Day09);
+        let part_runs = solve(&looping, "1105,1,0".to_string(), &BOTH_PARTS, false, Some(TIMEOUT)).parts.unwrap();
+        assert_eq!(part_runs.iter().map(|part_run| part_run.result.clone()).collect::<Vec<_>>(), vec![
+            Err(DayError::TimedOut(TIMEOUT)),
+            Err(DayError::TimedOut(TIMEOUT))
+        ]);
+
+        let next: Arc<dyn Solution> = Arc::new(Day01);
+        let part_runs = solve(&next, "12\n14".to_string(), &BOTH_PARTS, false, Some(TIMEOUT)).parts.unwrap();
+        assert_eq!(part_runs[0].result, Ok(Answer::from(4)));
+    }
+
+    #[test]
+    fn an_abandoned_program_stops_at_its_next_step () {
+        let (sender, stopped) = mpsc::channel();
+        let result = on_worker(TIMEOUT, move || {
+            let mut program = int_code::create_program("1105,1,0").unwrap();
+            program.run_until_stopped();
+            let _ = sender.send(program.get_status());
+        });
+        assert_eq!(result.map_err(|(error, _)| error), Err(DayError::TimedOut(TIMEOUT)));
+        match stopped.recv_timeout(Duration::from_secs(5)) {
+            Ok(IntCodeProgramStatus::Faulted(fault)) => assert_eq!(fault.reason, FaultReason::Cancelled),
+            status => panic!("expected the program to be cancelled but got {:?}", status)
+        }
+    }
+}
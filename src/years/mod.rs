@@ -0,0 +1,50 @@
+use crate::day_tasks::Solution;
+use std::sync::Arc;
+
+pub mod y2019;
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub enum DaySelection {
+    All,
+    Range(u32, u32)
+}
+
+impl DaySelection {
+    pub fn contains (&self, day: u32) -> bool {
+        match self {
+            DaySelection::All => true,
+            DaySelection::Range(from, to) => *from <= day && day <= *to
+        }
+    }
+}
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub enum YearSelection {
+    Latest,
+    All,
+    Year(u32)
+}
+
+pub fn all () -> Vec<Arc<dyn Solution>> {
+    let mut days = Vec::new();
+    days.extend(y2019::days::all());
+    days
+}
+
+pub fn latest_year () -> Option<u32> {
+    all().iter().filter_map(|day| day.year().parse::<u32>().ok()).max()
+}
+
+pub fn select (years: &YearSelection, days: &DaySelection) -> Vec<Arc<dyn Solution>> {
+    let all_days = all();
+    let latest_year = all_days.iter().map(|day| day.year()).max().unwrap_or_default();
+    all_days
+        .into_iter()
+        .filter(|day| match years {
+            YearSelection::Latest => day.year() == latest_year,
+            YearSelection::All => true,
+            YearSelection::Year(year) => day.year() == year.to_string()
+        })
+        .filter(|day| days.contains(day.day_number().parse::<u32>().unwrap_or(0)))
+        .collect()
+}
@@ -0,0 +1,125 @@
+use crate::day_error::DayError;
+use super::int_code;
+use std::io::{self, BufRead};
+
+#[derive(Clone)]
+pub struct AsciiCodeProgram {
+    int_code_program: int_code::IntCodeProgram
+}
+
+impl AsciiCodeProgram {
+    /// Feeds the script to the program and runs it until it halts or waits for more input.
+    pub fn run_script (&mut self, script: &str) -> (String, Option<i128>) {
+        self.push_script_as_input(script);
+        self.run_until_waiting()
+    }
+
+    /// Returns the printed ASCII text and the last output value outside of the ASCII range, if any.
+    pub fn run_until_waiting (&mut self) -> (String, Option<i128>) {
+        let mut text = String::new();
+        let mut non_ascii_output = None;
+        while let Some(c_as_i) = self.int_code_program.run_until_next_output() {
+            if c_as_i >= 128 {
+                non_ascii_output = Some(c_as_i);
+            }
+            else {
+                text.push(c_as_i as u8 as char);
+            }
+        }
+        (text, non_ascii_output)
+    }
+
+    /// The IntCode program behind the ASCII interface, to debug or trace it.
+    pub fn int_code_program (&self) -> &int_code::IntCodeProgram {
+        &self.int_code_program
+    }
+
+    pub fn int_code_program_mut (&mut self) -> &mut int_code::IntCodeProgram {
+        &mut self.int_code_program
+    }
+
+    pub fn into_inner (self) -> int_code::IntCodeProgram {
+        self.int_code_program
+    }
+
+    pub fn get_status (&self) -> int_code::IntCodeProgramStatus {
+        self.int_code_program.get_status()
+    }
+
+    pub fn check (&self) -> Result<(), int_code::VmFault> {
+        self.int_code_program.check()
+    }
+
+    pub fn push_script_as_input (&mut self, script: &str) {
+        for c in script.chars() {
+            self.int_code_program.push_input(c as i128);
+        }
+    }
+
+    /// Plays the program on the terminal and returns everything it printed, until it halts or faults.
+    /// Fails when stdin ends or can't be read while the program waits for input.
+    pub fn run_and_prompt_user_input (&mut self, user_input_mapping: &dyn Fn(&str) -> String) -> Result<String, DayError> {
+        self.run_and_prompt(io::stdin().lock(), user_input_mapping)
+    }
+
+    fn run_and_prompt (&mut self, mut user_input: impl BufRead, user_input_mapping: &dyn Fn(&str) -> String) -> Result<String, DayError> {
+        let mut transcript = String::new();
+        loop {
+            let mut output_occured = false;
+            while let Some(c_as_i) = self.int_code_program.run_until_next_output() {
+                output_occured = true;
+                let output = if c_as_i >= 128 { c_as_i.to_string() } else { (c_as_i as u8 as char).to_string() };
+                print!("{}", output);
+                transcript.push_str(&output);
+            }
+            if output_occured { println!(); }
+
+            match self.int_code_program.get_status() {
+                int_code::IntCodeProgramStatus::WaitingForInput => {
+                    let mut input = String::new();
+                    match user_input.read_line(&mut input) {
+                        Ok(0) => return Err(DayError::unsolvable("the input ended before the program halted")),
+                        Ok(_) => {
+                            let mapped_input = user_input_mapping(&input);
+                            println!("{}", mapped_input);
+                            for c in mapped_input.chars() {
+                                self.int_code_program.push_input(c as i128)
+                            }
+                        }
+                        Err(error) => {
+                            eprintln!("error: {}", error);
+                            return Err(DayError::unsolvable("the input can't be read"));
+                        }
+                    }
+                },
+                int_code::IntCodeProgramStatus::Halt | int_code::IntCodeProgramStatus::Faulted(_) => {
+                    break;
+                },
+                _ => ()
+            }
+        }
+        Ok(transcript)
+    }
+}
+
+pub fn create_program (text_code: &str) -> Result<AsciiCodeProgram, DayError> {
+    Ok(AsciiCodeProgram { int_code_program: int_code::create_program(text_code)? })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // IN -> [0]; HLT
+    const READ_ONE: &str = "3,0,99";
+
+    #[test]
+    fn stops_prompting_when_the_input_ends () {
+        let mut program = create_program(READ_ONE).unwrap();
+        assert_eq!(
+            program.run_and_prompt("".as_bytes(), &|input| input.to_string()),
+            Err(DayError::unsolvable("the input ended before the program halted")));
+        let mut program = create_program(READ_ONE).unwrap();
+        assert_eq!(program.run_and_prompt("n\n".as_bytes(), &|input| input.to_string()), Ok(String::new()));
+    }
+}
@@ -0,0 +1,302 @@
+use super::disassembler;
+use super::int_code::Instruction;
+use itertools::Itertools;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::HashMap;
+
+lazy_static! {
+    static ref LABEL: Regex = Regex::new(r"^([A-Za-z_][A-Za-z0-9_]*)\s*:").unwrap();
+    static ref SYMBOL: Regex = Regex::new(r"^-?[A-Za-z_][A-Za-z0-9_]*$").unwrap();
+    static ref RELATIVE: Regex = Regex::new(r"^\[\s*rb\s*(?:([+-])\s*([^\s\]]+))?\s*\]$").unwrap();
+    static ref POSITION: Regex = Regex::new(r"^\[\s*([^\s\]]+)\s*\]$").unwrap();
+}
+
+/// A number or the name of a label or constant, which may be negated.
+#[derive(Eq, PartialEq, Clone, Debug)]
+enum Value {
+    Number(i128),
+    Symbol { name: String, negated: bool }
+}
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+enum Operand {
+    Position(Value),
+    Immediate(Value),
+    Relative(Value)
+}
+
+/// What a line puts into memory, its symbols are resolved once all labels are known.
+#[derive(Eq, PartialEq, Clone, Debug)]
+enum Item {
+    Instruction(Instruction, Vec<Operand>),
+    Data(Vec<Value>)
+}
+
+impl Item {
+    fn length (&self) -> usize {
+        match self {
+            Item::Instruction(_, operands) => operands.len() + 1,
+            Item::Data(values) => values.len()
+        }
+    }
+}
+
+/// Assembles `source` into the comma separated format `int_code::create_program` reads.
+///
+/// Each line holds an instruction in the disassembler's syntax (`ADD [rb+3], #5 -> [100]`), a directive or nothing,
+/// optionally after one or more `<label>:` and before a `;` comment. Operands are `[<value>]` in position mode,
+/// `#<value>` in immediate mode and `[rb+<value>]` or `[rb-<value>]` relative to the relative base, a bare symbol
+/// is immediate like the jump targets of the disassembler. Values are numbers or symbols, both may be negated.
+/// The directives are `.const <NAME> <NUMBER>`, `.data <value>, ...` and `.string "<text>"`, which puts the
+/// character codes of the text into memory and knows the escapes `\n`, `\t`, `\"` and `\\`.
+pub fn assemble (source: &str) -> Result<String, String> {
+    Ok(assemble_memory(source)?.iter().join(","))
+}
+
+pub fn assemble_memory (source: &str) -> Result<Vec<i128>, String> {
+    let mut symbols = HashMap::new();
+    let mut items = Vec::new();
+    let mut address = 0;
+    for (index, line) in source.lines().enumerate() {
+        let error = |message: String| format!("line {}: {}", index + 1, message);
+        let mut text = strip_comment(line).trim();
+        while let Some(captures) = LABEL.captures(text) {
+            define(&mut symbols, &captures[1], address as i128).map_err(error)?;
+            text = text[captures[0].len()..].trim_start();
+        }
+        if text.is_empty() { continue; }
+        if let Some(item) = parse_statement(text, &mut symbols).map_err(error)? {
+            address += item.length();
+            items.push((index + 1, item));
+        }
+    }
+    let mut memory = Vec::with_capacity(address);
+    for (line_number, item) in items {
+        let resolve = |value: &Value| resolve(value, &symbols).map_err(|message| format!("line {}: {}", line_number, message));
+        match item {
+            Item::Instruction(instruction, operands) => {
+                let mut op_code = instruction.op_code();
+                let mut values = Vec::new();
+                for (index, operand) in operands.iter().enumerate() {
+                    let (mode, value) = match operand {
+                        Operand::Position(value) => (0, value),
+                        Operand::Immediate(value) => (1, value),
+                        Operand::Relative(value) => (2, value)
+                    };
+                    op_code += mode * 10i128.pow(index as u32 + 2);
+                    values.push(resolve(value)?);
+                }
+                memory.push(op_code);
+                memory.extend(values);
+            }
+            Item::Data(values) => {
+                for value in &values {
+                    memory.push(resolve(value)?);
+                }
+            }
+        }
+    }
+    Ok(memory)
+}
+
+/// Everything before a `;` that isn't inside a string.
+fn strip_comment (line: &str) -> &str {
+    let mut in_string = false;
+    let mut escaped = false;
+    for (index, character) in line.char_indices() {
+        match character {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            ';' if !in_string => return &line[..index],
+            _ => {}
+        }
+    }
+    line
+}
+
+fn define (symbols: &mut HashMap<String, i128>, name: &str, value: i128) -> Result<(), String> {
+    if name == "rb" {
+        return Err("'rb' is the relative base and can't be defined".to_string());
+    }
+    if symbols.insert(name.to_string(), value).is_some() {
+        return Err(format!("'{}' is already defined", name));
+    }
+    Ok(())
+}
+
+fn resolve (value: &Value, symbols: &HashMap<String, i128>) -> Result<i128, String> {
+    match value {
+        Value::Number(number) => Ok(*number),
+        Value::Symbol { name, negated } => symbols
+            .get(name)
+            .map(|value| if *negated { -value } else { *value })
+            .ok_or_else(|| format!("'{}' is not defined", name))
+    }
+}
+
+/// Constants are defined right away and put nothing into memory.
+fn parse_statement (text: &str, symbols: &mut HashMap<String, i128>) -> Result<Option<Item>, String> {
+    let (head, rest) = text
+        .split_once(char::is_whitespace)
+        .map(|(head, rest)| (head, rest.trim()))
+        .unwrap_or((text, ""));
+    match head {
+        ".const" => {
+            let (name, value) = rest
+                .split_once(char::is_whitespace)
+                .ok_or_else(|| "expected '.const <NAME> <NUMBER>'".to_string())?;
+            if !LABEL.is_match(&format!("{}:", name)) {
+                return Err(format!("'{}' is not a valid name", name));
+            }
+            let value = value.trim().parse::<i128>().map_err(|_| format!("'{}' is not a number", value.trim()))?;
+            define(symbols, name, value).map(|_| None)
+        }
+        ".data" if rest.is_empty() => Err("'.data' needs at least one value".to_string()),
+        ".data" => rest
+            .split(',')
+            .map(|value| parse_value(value.trim()))
+            .collect::<Result<Vec<Value>, String>>()
+            .map(|values| Some(Item::Data(values))),
+        ".string" => parse_string(rest).map(|codes| Some(Item::Data(codes.into_iter().map(Value::Number).collect()))),
+        directive if directive.starts_with('.') => Err(format!("unknown directive '{}'", directive)),
+        mnemonic => {
+            let instruction = disassembler::parse_mnemonic(mnemonic).ok_or_else(|| format!("unknown mnemonic '{}'", mnemonic))?;
+            parse_operands(instruction, rest).map(|operands| Some(Item::Instruction(instruction, operands)))
+        }
+    }
+}
+
+fn parse_operands (instruction: Instruction, text: &str) -> Result<Vec<Operand>, String> {
+    let mnemonic = disassembler::mnemonic(instruction);
+    let (sources, destination) = match text.split_once("->") {
+        Some((sources, destination)) => (sources.trim(), Some(destination.trim())),
+        None => (text, None)
+    };
+    let mut operands = if sources.is_empty() { Vec::new() } else {
+        sources.split(',').map(|operand| parse_operand(operand.trim())).collect::<Result<Vec<Operand>, String>>()?
+    };
+    match destination {
+        Some(destination) if instruction.writes() => match parse_operand(destination)? {
+            Operand::Immediate(_) => return Err(format!("'{}' can't be written to in immediate mode", destination)),
+            destination => operands.push(destination)
+        },
+        Some(_) => return Err(format!("{} doesn't write, it takes no '->'", mnemonic)),
+        None if instruction.writes() => return Err(format!("{} writes, its last operand follows '->'", mnemonic)),
+        None => {}
+    }
+    if operands.len() != instruction.parameter_count() {
+        return Err(format!("{} takes {} operands, not {}", mnemonic, instruction.parameter_count(), operands.len()));
+    }
+    Ok(operands)
+}
+
+fn parse_operand (text: &str) -> Result<Operand, String> {
+    if let Some(value) = text.strip_prefix('#') {
+        return parse_value(value.trim()).map(Operand::Immediate);
+    }
+    if let Some(captures) = RELATIVE.captures(text) {
+        return match (captures.get(1).map(|sign| sign.as_str()), captures.get(2)) {
+            (Some("-"), Some(value)) => parse_value(&format!("-{}", value.as_str())),
+            (_, Some(value)) => parse_value(value.as_str()),
+            _ => Ok(Value::Number(0))
+        }.map(Operand::Relative);
+    }
+    if let Some(captures) = POSITION.captures(text) {
+        return parse_value(&captures[1]).map(Operand::Position);
+    }
+    if SYMBOL.is_match(text) {
+        return parse_value(text).map(Operand::Immediate);
+    }
+    Err(format!("'{}' is not an operand like [100], #5, [rb+3] or a label", text))
+}
+
+fn parse_value (text: &str) -> Result<Value, String> {
+    if let Ok(number) = text.parse::<i128>() {
+        return Ok(Value::Number(number));
+    }
+    if !SYMBOL.is_match(text) {
+        return Err(format!("'{}' is not a number or a name", text));
+    }
+    match text.strip_prefix('-') {
+        Some(name) => Ok(Value::Symbol { name: name.to_string(), negated: true }),
+        None => Ok(Value::Symbol { name: text.to_string(), negated: false })
+    }
+}
+
+fn parse_string (text: &str) -> Result<Vec<i128>, String> {
+    let inner = text
+        .strip_prefix('"')
+        .and_then(|text| text.strip_suffix('"'))
+        .ok_or_else(|| format!("'{}' is not a quoted string", text))?;
+    let mut codes = Vec::new();
+    let mut characters = inner.chars();
+    while let Some(character) = characters.next() {
+        let character = match character {
+            '\\' => match characters.next() {
+                Some('n') => '\n',
+                Some('t') => '\t',
+                Some('"') => '"',
+                Some('\\') => '\\',
+                Some(other) => return Err(format!("unknown escape '\\{}'", other)),
+                None => return Err("the string ends in a lone '\\'".to_string())
+            },
+            '"' => return Err("quotes inside strings need to be escaped as '\\\"'".to_string()),
+            character => character
+        };
+        codes.push(character as i128);
+    }
+    Ok(codes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::int_code;
+
+    const GREETING: &str = "
+.const NEWLINE 10
+        ARB #message        ; rb walks along the message
+loop:   JZ [rb+0], end
+        OUT [rb]
+        ARB #1
+        JZ #0, loop
+end:    OUT #NEWLINE
+        HLT
+message:
+        .string \"Hi\"      ; until the 0 below
+        .data 0
+";
+
+    #[test]
+    fn assembles_labels_constants_and_directives () {
+        assert_eq!(assemble(GREETING).unwrap(), "109,15,1206,0,12,204,0,109,1,1106,0,2,104,10,99,72,105,0");
+        let mut program = int_code::create_program(&assemble(GREETING).unwrap()).unwrap();
+        let outputs = std::iter::from_fn(|| program.run_until_next_output()).collect::<Vec<i128>>();
+        assert_eq!(outputs, vec![72, 105, 10]);
+        assert_eq!(assemble_memory("ADD [-x], #x -> [rb-x]\n.const x 4"), Ok(vec![21001, -4, 4, -4]));
+    }
+
+    #[test]
+    fn round_trips_through_the_disassembler () {
+        let memory = assemble_memory(GREETING).unwrap();
+        let source = disassembler::disassemble(&memory).to_source();
+        assert_eq!(assemble_memory(&source), Ok(memory));
+        // the second jump targets the middle of the ADD at 8, which has no line to label
+        let memory = vec![1105, 1, 5, 1, 0, 1105, 1, 9, 1101, 0, 0, 20, 99];
+        let source = disassembler::disassemble(&memory).to_source();
+        assert_eq!(assemble_memory(&source), Ok(memory));
+    }
+
+    #[test]
+    fn reports_the_line_of_errors () {
+        assert_eq!(assemble("HLT\nMOV #1 -> [0]"), Err("line 2: unknown mnemonic 'MOV'".to_string()));
+        assert_eq!(assemble("JZ #0, nowhere"), Err("line 1: 'nowhere' is not defined".to_string()));
+        assert_eq!(assemble("IN -> #3"), Err("line 1: '#3' can't be written to in immediate mode".to_string()));
+        assert_eq!(assemble("ADD #1, #2"), Err("line 1: ADD writes, its last operand follows '->'".to_string()));
+        assert_eq!(assemble("OUT #1, #2"), Err("line 1: OUT takes 1 operands, not 2".to_string()));
+        assert_eq!(assemble("a: HLT\na: HLT"), Err("line 2: 'a' is already defined".to_string()));
+        assert_eq!(assemble(".string \"a\\qb\""), Err("line 1: unknown escape '\\q'".to_string()));
+    }
+}
@@ -0,0 +1,82 @@
+use crate::answer::Answer;
+use crate::day_error::DayError;
+use crate::day_tasks;
+use crate::input;
+
+pub struct Day01;
+
+impl day_tasks::DayTasks for Day01 {
+    type Input = Vec<i32>;
+
+    fn year (&self) -> String {
+        "2019".to_string()
+    }
+    fn day_number (&self) -> String {
+        "01".to_string()
+    }
+    fn parse (&self, input: &str) -> Result<Vec<i32>, DayError> {
+        parse_masses(input)
+    }
+    fn task_0 (&self, masses: &Vec<i32>) -> Result<Answer, DayError> {
+        let result: i32 = masses
+            .iter()
+            .copied()
+            .map(fuel_calculation)
+            .sum();
+        Ok(Answer::from(result))
+    }
+    fn task_1 (&self, masses: &Vec<i32>) -> Result<Answer, DayError> {
+        let result : i32 = 
+        masses
+            .iter()
+            .map(|mass| {
+                let module_mass_fuel = fuel_calculation(*mass);
+                let sum : i32 = std::iter::successors(Some(module_mass_fuel), |current_mass| {
+                    let next_mass = fuel_calculation(*current_mass);
+                    if next_mass > 0 {
+                        Some(next_mass)
+                    }
+                    else {
+                        None
+                    }
+                }).sum();
+                sum
+            })
+            .sum();
+        Ok(Answer::from(result))
+    }
+}
+
+fn fuel_calculation(mass : i32) -> i32 {
+    mass / 3 - 2
+}
+
+fn parse_masses (input: &str) -> Result<Vec<i32>, DayError> {
+    input::lines(input)
+        .map(|(line_number, line)| line
+            .parse::<i32>()
+            .map_err(|_| DayError::parse(line_number, format!("'{}' is not a mass", line))))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_tasks::Part;
+    use crate::examples::{self, Example};
+
+    const EXAMPLES: &[Example] = &[
+        ("12", Part::First, "2"),
+        ("14", Part::First, "2"),
+        ("1969", Part::First, "654"),
+        ("100756", Part::First, "33583"),
+        ("14", Part::Second, "2"),
+        ("1969", Part::Second, "966"),
+        ("100756", Part::Second, "50346")
+    ];
+
+    #[test]
+    fn worked_examples () {
+        examples::check(&Day01, EXAMPLES);
+    }
+}
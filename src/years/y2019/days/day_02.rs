@@ -0,0 +1,72 @@
+use crate::answer::Answer;
+use crate::day_error::DayError;
+use crate::day_tasks;
+use super::int_code;
+
+pub struct Day02;
+
+impl day_tasks::DayTasks for Day02 {
+    type Input = int_code::IntCodeProgram;
+
+    fn year (&self) -> String {
+        "2019".to_string()
+    }
+    fn day_number (&self) -> String {
+        "02".to_string()
+    }
+    fn parse (&self, input: &str) -> Result<int_code::IntCodeProgram, DayError> {
+        int_code::create_program(input)
+    }
+    fn task_0 (&self, program: &int_code::IntCodeProgram) -> Result<Answer, DayError> {
+        Ok(Answer::from(iteration(program, 12, 2)?))
+    }
+    fn task_1 (&self, program: &int_code::IntCodeProgram) -> Result<Answer, DayError> {
+        let (noun, verb) = find_output(program, 19690720)?;
+        let result = 100 * noun + verb;
+        Ok(Answer::from(result))
+    }
+}
+
+fn find_output (program: &int_code::IntCodeProgram, seeked_output: i128) -> Result<(i128, i128), DayError> {
+    for noun in 0..=99 {
+        for verb in 0..=99 {
+            match iteration(program, noun, verb) {
+                Ok(output) if output == seeked_output => return Ok((noun, verb)),
+                // patches which make the program fault just aren't the one
+                Ok(_) | Err(DayError::VmFault(_)) => {}
+                Err(error) => return Err(error)
+            }
+        }
+    }
+    Err(DayError::unsolvable(format!("no noun and verb produce {}", seeked_output)))
+}
+
+fn iteration(program: &int_code::IntCodeProgram, noun: i128, verb: i128) -> Result<i128, DayError> {
+    let mut program = program.clone();
+    program.day_02_initialize(noun, verb)?;
+    program.run_until_stopped();
+    program.check()?;
+    Ok(program.day_02_result())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_tasks::Part;
+    use crate::examples::{self, Example};
+
+    // no worked example patches the program, this one computes 100 * noun + verb + 19686153 from cells 1 and 2:
+    //     ADD #noun, #verb -> [20]; MUL [1], #100 -> [0]; ADD [0], [2] -> [0]; ADD [0], #19686153 -> [0]; HLT
+    const PROGRAM: &str = "1101,0,0,20,1002,1,100,0,1,0,2,0,1001,0,19686153,0,99";
+
+    const EXAMPLES: &[Example] = &[
+        (PROGRAM, Part::First, "19687355"),
+        (PROGRAM, Part::Second, "4567"),
+        ("1,9,10,3,2,3,11,0,99,30,40,50", Part::First, "100")
+    ];
+
+    #[test]
+    fn worked_examples () {
+        examples::check(&Day02, EXAMPLES);
+    }
+}
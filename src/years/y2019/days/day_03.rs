@@ -0,0 +1,159 @@
+use crate::answer::Answer;
+use crate::day_error::DayError;
+use crate::day_tasks;
+use crate::input;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::iter;
+
+type Path = Vec<(i32, i32)>;
+
+pub struct Day03;
+
+#[derive(Eq, PartialEq,Hash)]
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right
+}
+
+struct Instruction {
+    direction: Direction,
+    steps: i32
+}
+
+impl day_tasks::DayTasks for Day03 {
+    type Input = (Path, Path);
+
+    fn year (&self) -> String {
+        "2019".to_string()
+    }
+    fn day_number (&self) -> String {
+        "03".to_string()
+    }
+    fn parse (&self, input: &str) -> Result<(Path, Path), DayError> {
+        get_paths(input)
+    }
+    fn task_0 (&self, paths: &(Path, Path)) -> Result<Answer, DayError> {
+        let (points_0, points_1) = paths;
+        let hash_set_0: HashSet<(i32, i32)> = points_0.iter().copied().collect();
+        let hash_set_1: HashSet<(i32, i32)> = points_1.iter().copied().collect();
+        let result_maybe = hash_set_0
+            .intersection(&hash_set_1)
+            .map(|(x, y)| x.abs() + y.abs() )
+            .min();
+        result_maybe
+            .map(Answer::from)
+            .ok_or_else(|| DayError::unsolvable("the wires don't cross"))
+    }
+    fn task_1 (&self, paths: &(Path, Path)) -> Result<Answer, DayError> {
+        let (points_0, points_1) = paths;
+        let hash_set_0: HashSet<(i32, i32)> = points_0.iter().copied().collect();
+        let hash_set_1: HashSet<(i32, i32)> = points_1.iter().copied().collect();
+        let intersections: HashSet<&(i32, i32)> = hash_set_0
+            .intersection(&hash_set_1)
+            .collect();
+        let distances_0 = get_distances_to_intersections(points_0, &intersections);
+        let distances_1 = get_distances_to_intersections(points_1, &intersections);
+        intersections
+            .into_iter()
+            .map(|intersection| distances_0[intersection] + distances_1[intersection])
+            .min()
+            .map(Answer::from)
+            .ok_or_else(|| DayError::unsolvable("the wires don't cross"))
+    }
+}
+
+fn get_distances_to_intersections (path: &[(i32, i32)], intersections: &HashSet<&(i32, i32)>) -> HashMap<(i32, i32), i32> {
+    let mut i = 0;
+    let mut distances_to_intersections: HashMap<(i32, i32), i32> = HashMap::new();
+    for element in path {
+        i += 1;
+        if intersections.contains(&element) && !distances_to_intersections.contains_key(element) {
+            distances_to_intersections.insert((element.0, element.1), i);
+        }
+    }
+    distances_to_intersections
+}
+
+fn get_paths (input: &str) -> Result<(Path, Path), DayError> {
+    let cables: Vec<Vec<Instruction>> = input::lines(input)
+        .map(|(line_number, line)| line
+            .split(',')
+            .map(|text_instruction| parse_to_instruction(text_instruction)
+                .ok_or_else(|| DayError::parse(line_number, format!("'{}' is not a wire instruction", text_instruction))))
+            .collect::<Result<Vec<Instruction>, DayError>>())
+        .collect::<Result<Vec<Vec<Instruction>>, DayError>>()?;
+        if cables.len() == 2 {
+            let points_0 = get_points(&cables[0]);
+            let points_1 = get_points(&cables[1]);
+            Ok((points_0, points_1))
+        }
+        else { Err(DayError::parse(cables.len() + 1, format!("expected two wires but found {}", cables.len()))) }
+}
+
+fn get_points(instructions: &[Instruction]) -> Path {
+    let map: HashMap<Direction, (i32, i32)> = 
+        vec![(Direction::Up, (0, 1)), (Direction::Down, (0, -1)), (Direction::Left, (-1, 0)), (Direction::Right, (1, 0))]
+        .into_iter()
+        .collect();
+    instructions
+        .iter()
+        .scan((0, 0), |(s_x, s_y), instruction| {
+            let (d_x, d_y) = map[&(instruction.direction)];
+            let (temp_x, temp_y) = (*s_x, *s_y);
+            *s_x += d_x * instruction.steps;
+            *s_y += d_y * instruction.steps;
+            Some(((temp_x, temp_y), (d_x, d_y), instruction.steps))
+        })
+        .flat_map(|((s_x, s_y), (d_x, d_y), steps)| {
+            iter::repeat_n((d_x, d_y), steps as usize)
+                .scan((s_x, s_y), |(curr_x, curr_y), (d_x, d_y)| {
+                    *curr_x += d_x;
+                    *curr_y += d_y;
+                    Some((*curr_x, *curr_y))
+                })
+        })
+        .collect()
+}
+
+fn parse_to_instruction(text: &str) -> Option<Instruction> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new("(U|D|L|R)(\\d+)").unwrap();
+    }
+    RE
+        .captures(text)
+        .and_then(|captures| {
+            let direction = match captures.get(1)?.as_str() { 
+                "U" =>  Direction::Up,
+                "D" =>  Direction::Down,
+                "L" =>  Direction::Left,
+                _   =>  Direction::Right};
+            let steps = captures.get(2)?.as_str().parse::<i32>().ok()?;
+            Some(Instruction { direction, steps })
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_tasks::Part;
+    use crate::examples::{self, Example};
+
+    const EXAMPLES: &[Example] = &[
+        ("R8,U5,L5,D3\nU7,R6,D4,L4", Part::First, "6"),
+        ("R75,D30,R83,U83,L12,D49,R71,U7,L72\nU62,R66,U55,R34,D71,R55,D58,R83", Part::First, "159"),
+        ("R98,U47,R26,D63,R33,U87,L62,D20,R33,U53,R51\nU98,R91,D20,R16,D67,R40,U7,R15,U6,R7", Part::First, "135"),
+        ("R8,U5,L5,D3\nU7,R6,D4,L4", Part::Second, "30"),
+        ("R75,D30,R83,U83,L12,D49,R71,U7,L72\nU62,R66,U55,R34,D71,R55,D58,R83", Part::Second, "610"),
+        ("R98,U47,R26,D63,R33,U87,L62,D20,R33,U53,R51\nU98,R91,D20,R16,D67,R40,U7,R15,U6,R7", Part::Second, "410")
+    ];
+
+    #[test]
+    fn worked_examples () {
+        examples::check(&Day03, EXAMPLES);
+    }
+}
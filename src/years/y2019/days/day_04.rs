@@ -0,0 +1,92 @@
+use crate::answer::Answer;
+use crate::day_error::DayError;
+use crate::day_tasks;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::ops::RangeInclusive;
+
+pub struct Day04;
+
+type AdjacencyCriterion = dyn Fn(char, char, char, char, char, char) -> bool;
+
+impl day_tasks::DayTasks for Day04 {
+    type Input = RangeInclusive<i32>;
+
+    fn year (&self) -> String {
+        "2019".to_string()
+    }
+    fn day_number (&self) -> String {
+        "04".to_string()
+    }
+    fn parse (&self, input: &str) -> Result<RangeInclusive<i32>, DayError> {
+        parse_to_range(input)
+            .ok_or_else(|| DayError::parse(1, format!("'{}' is not a range like 123456-654321", input.trim())))
+    }
+    fn task_0 (&self, range: &RangeInclusive<i32>) -> Result<Answer, DayError> {
+        fn adjacency_criterion (s_0: char, s_1: char, s_2: char, s_3: char, s_4: char, s_5: char) -> bool {
+            s_0 == s_1 || s_1 == s_2 || s_2 == s_3 || s_3 == s_4 || s_4 == s_5
+        }
+        Ok(Answer::from(count_of_possible_passwords(range.clone(), &adjacency_criterion)))
+    }
+    fn task_1 (&self, range: &RangeInclusive<i32>) -> Result<Answer, DayError> {
+        fn adjacency_criterion (s_0: char, s_1: char, s_2: char, s_3: char, s_4: char, s_5: char) -> bool {
+            s_0 == s_1 && s_1 != s_2 
+            || s_1 == s_2 && s_0 != s_1 && s_2 != s_3 
+            || s_2 == s_3 && s_1 != s_2 && s_3 != s_4 
+            || s_3 == s_4 && s_2 != s_3 && s_4 != s_5
+            || s_4 == s_5 && s_3 != s_4
+        }
+        Ok(Answer::from(count_of_possible_passwords(range.clone(), &adjacency_criterion)))
+    }
+}
+
+fn count_of_possible_passwords (range: RangeInclusive<i32>, adjacency_criterion: &AdjacencyCriterion) -> usize {
+    range
+        .map(|i| i.to_string())
+        .filter(|s| {
+            let mut chars = s.chars();
+            let s_0 = chars.next().unwrap_or_default();
+            let s_1 = chars.next().unwrap_or_default();
+            let s_2 = chars.next().unwrap_or_default();
+            let s_3 = chars.next().unwrap_or_default();
+            let s_4 = chars.next().unwrap_or_default();
+            let s_5 = chars.next().unwrap_or_default();
+            s_0 <= s_1 && s_1 <= s_2 && s_2 <= s_3 && s_3 <= s_4 && s_4 <= s_5
+            && adjacency_criterion(s_0, s_1, s_2, s_3, s_4, s_5)
+        })
+        .count()
+}
+
+fn parse_to_range(text: &str) -> Option<RangeInclusive<i32>> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new("(\\d+)-(\\d+)").unwrap();
+    }
+    RE
+        .captures(text)
+        .and_then(|captures| {
+            let lower_bound = captures.get(1)?.as_str().parse::<i32>().ok()?;
+            let higher_bound = captures.get(2)?.as_str().parse::<i32>().ok()?;
+            Some(lower_bound..=higher_bound)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_tasks::Part;
+    use crate::examples::{self, Example};
+
+    const EXAMPLES: &[Example] = &[
+        ("111111-111111", Part::First, "1"),
+        ("223450-223450", Part::First, "0"),
+        ("123789-123789", Part::First, "0"),
+        ("112233-112233", Part::Second, "1"),
+        ("123444-123444", Part::Second, "0"),
+        ("111122-111122", Part::Second, "1")
+    ];
+
+    #[test]
+    fn worked_examples () {
+        examples::check(&Day04, EXAMPLES);
+    }
+}
@@ -0,0 +1,61 @@
+use crate::answer::Answer;
+use crate::day_error::DayError;
+use crate::day_tasks;
+use super::int_code;
+
+pub struct Day05;
+
+impl day_tasks::DayTasks for Day05 {
+    type Input = int_code::IntCodeProgram;
+
+    fn year (&self) -> String {
+        "2019".to_string()
+    }
+    fn day_number (&self) -> String {
+        "05".to_string()
+    }
+    fn parse (&self, input: &str) -> Result<int_code::IntCodeProgram, DayError> {
+        int_code::create_program(input)
+    }
+    fn task_0 (&self, program: &int_code::IntCodeProgram) -> Result<Answer, DayError> {
+        iteration(program, 1)
+    }
+    fn task_1 (&self, program: &int_code::IntCodeProgram) -> Result<Answer, DayError> {
+        iteration(program, 5)
+    }
+}
+
+fn iteration(program: &int_code::IntCodeProgram, input: i128) -> Result<Answer, DayError> {
+    let mut program = program.clone();
+    program.run_until_stopped();
+    while program.get_status() == int_code::IntCodeProgramStatus::WaitingForInput {
+        program.push_input(input);
+        program.run_until_stopped();
+    }
+    program
+        .get_last_output()
+        .map(Answer::from)
+        .ok_or_else(|| program.unexpected_stop("the program halted without any output"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_tasks::Part;
+    use crate::examples::{self, Example};
+
+    const EXAMPLES: &[Example] = &[
+        ("3,0,4,0,99", Part::First, "1"),
+        ("3,9,8,9,10,9,4,9,99,-1,8", Part::Second, "0"),
+        ("3,9,7,9,10,9,4,9,99,-1,8", Part::Second, "1"),
+        ("3,3,1108,-1,8,3,4,3,99", Part::Second, "0"),
+        ("3,3,1107,-1,8,3,4,3,99", Part::Second, "1"),
+        ("3,12,6,12,15,1,13,14,13,4,13,99,-1,0,1,9", Part::Second, "1"),
+        ("3,21,1008,21,8,20,1005,20,22,107,8,21,20,1006,20,31,1106,0,36,98,0,0,1002,21,125,20,4,20,1105,1,46,104,999,1105,1,46,1101,1000,1,20,4,20,1105,1,46,98,99", Part::Second, "999")
+    ];
+
+    #[test]
+    fn worked_examples () {
+        examples::check(&Day05, EXAMPLES);
+    }
+}
@@ -0,0 +1,111 @@
+use crate::answer::Answer;
+use crate::day_error::DayError;
+use crate::day_tasks;
+use crate::input;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::HashMap;
+use itertools::Itertools;
+
+type AdjacencyMap = HashMap<String, Vec<String>>;
+
+pub struct Day06;
+
+impl day_tasks::DayTasks for Day06 {
+    type Input = AdjacencyMap;
+
+    fn year (&self) -> String {
+        "2019".to_string()
+    }
+    fn day_number (&self) -> String {
+        "06".to_string()
+    }
+    fn parse (&self, input: &str) -> Result<AdjacencyMap, DayError> {
+        generate_adjacency_map(input)
+    }
+    fn task_0 (&self, adjacency_map: &AdjacencyMap) -> Result<Answer, DayError> {
+        Ok(Answer::from(count_connections(adjacency_map, "COM".to_string()).1))
+    }
+    fn task_1 (&self, adjacency_map: &AdjacencyMap) -> Result<Answer, DayError> {
+        match determine_orbital_transfers_to_santa(adjacency_map, "COM".to_string()) {
+            (Some(you), Some(santa)) => Ok(Answer::from(you + santa)),
+            _ => Err(DayError::unsolvable("YOU and SAN don't both orbit COM"))
+        }
+    }
+}
+
+fn determine_orbital_transfers_to_santa(adjacency_map: &AdjacencyMap, current_node: String) -> (Option<i32>, Option<i32>) {
+    if current_node == "YOU" { (Some(0), None) }
+    else if current_node == "SAN" { (None, Some(0)) }
+    else if adjacency_map.contains_key(&current_node) {
+        let vec = &adjacency_map[&current_node];
+        let nodes = vec
+            .iter()
+            .map(|child| determine_orbital_transfers_to_santa(adjacency_map, child.to_string()))
+            .fold((None, None), |(current_node_you, current_node_santa), (child_node_you, child_node_santa)| 
+                (current_node_you.or(child_node_you), current_node_santa.or(child_node_santa)));
+        match nodes {
+            (Some(_), Some(_)) => nodes,
+            (Some(number), None) => (Some(number + 1), None),
+            (None, Some(number)) => (None, Some(number + 1)),
+            (None, None) => (None, None)
+        }
+    }
+    else { (None, None)}
+}
+
+fn count_connections(adjacency_map: &AdjacencyMap, current_node: String) -> (i32, i32) {
+    if adjacency_map.contains_key(&current_node) {
+        let vec = &adjacency_map[&current_node];
+        let (child_count, score) = vec
+            .iter()
+            .map(|child| count_connections(adjacency_map, child.to_string()))
+            .fold((0, 0), |(current_node_count, current_score), (child_node_count, child_score)| (current_node_count + child_node_count, current_score + child_score));
+        (child_count + 1, child_count + score)
+    }
+    else { (1, 0)}
+}
+
+fn generate_adjacency_map (input: &str) -> Result<AdjacencyMap, DayError> {
+    let orbits = input::lines(input)
+        .map(|(line_number, line)| parse_to_tuple(line)
+            .ok_or_else(|| DayError::parse(line_number, format!("'{}' is not an orbit like A)B", line))))
+        .collect::<Result<Vec<(String, String)>, DayError>>()?;
+    Ok(orbits
+        .into_iter()
+        .sorted()
+        .chunk_by(|(key, _)| key.to_string())
+        .into_iter()
+        .map(|(key, values)| (key.to_string(), values.map(|(_, value)| value).collect::<Vec<String>>()))
+        .collect())
+}
+
+fn parse_to_tuple(text: &str) -> Option<(String, String)> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new("(.*)\\)(.*)").unwrap();
+    }
+    RE
+        .captures(text)
+        .and_then(|captures| {
+            let left = captures.get(1)?.as_str().to_string();
+            let right = captures.get(2)?.as_str().to_string();
+            Some((left, right))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_tasks::Part;
+    use crate::examples::{self, Example};
+
+    const EXAMPLES: &[Example] = &[
+        ("COM)B\nB)C\nC)D\nD)E\nE)F\nB)G\nG)H\nD)I\nE)J\nJ)K\nK)L", Part::First, "42"),
+        ("COM)B\nB)C\nC)D\nD)E\nE)F\nB)G\nG)H\nD)I\nE)J\nJ)K\nK)L\nK)YOU\nI)SAN", Part::Second, "4")
+    ];
+
+    #[test]
+    fn worked_examples () {
+        examples::check(&Day06, EXAMPLES);
+    }
+}
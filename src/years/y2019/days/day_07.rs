@@ -0,0 +1,114 @@
+use crate::answer::Answer;
+use crate::day_error::DayError;
+use crate::day_tasks;
+use super::int_code;
+
+pub struct Day07;
+
+impl day_tasks::DayTasks for Day07 {
+    type Input = int_code::IntCodeProgram;
+
+    fn year (&self) -> String {
+        "2019".to_string()
+    }
+    fn day_number (&self) -> String {
+        "07".to_string()
+    }
+    fn parse (&self, input: &str) -> Result<int_code::IntCodeProgram, DayError> {
+        int_code::create_program(input)
+    }
+    fn task_0 (&self, program: &int_code::IntCodeProgram) -> Result<Answer, DayError> {
+        amplifier_sequence(program, &mut [0, 1, 2, 3, 4], &iteration_0)
+    }
+    fn task_1 (&self, program: &int_code::IntCodeProgram) -> Result<Answer, DayError> {
+        amplifier_sequence(program, &mut [5, 6, 7, 8, 9], &iteration_1)
+    }
+}
+
+type PhaseSettingIteration = dyn Fn(&int_code::IntCodeProgram, Vec<i128>) -> Result<i128, DayError>;
+
+fn amplifier_sequence(
+    program: &int_code::IntCodeProgram,
+    phase_setting_values: &mut [i128],
+    phase_setting_iteration: &PhaseSettingIteration) -> Result<Answer, DayError> {
+    let all_possible_phase_settings = get_all_possible_phase_settings(phase_setting_values);
+    let signals = all_possible_phase_settings
+        .into_iter()
+        .map(|phase_setting| phase_setting_iteration(program, phase_setting))
+        .collect::<Result<Vec<i128>, DayError>>()?;
+    signals
+        .into_iter()
+        .max()
+        .map(Answer::from)
+        .ok_or_else(|| DayError::unsolvable("there are no phase settings"))
+}
+
+fn iteration_1 (program: &int_code::IntCodeProgram, phase_setting: Vec<i128>) -> Result<i128, DayError> {
+    let programs: &mut [int_code::IntCodeProgram] = &mut [
+        program.clone(),
+        program.clone(),
+        program.clone(),
+        program.clone(),
+        program.clone()];
+    
+    let mut input = 0;
+    for (program, phase) in programs.iter_mut().zip(&phase_setting) {
+        program.push_input(*phase);
+    }
+    while programs[0].get_status() != int_code::IntCodeProgramStatus::Halt {
+        input = programs
+            .iter_mut()
+            .fold(input, |input, program| {
+                program.push_input(input);
+                program.run_until_next_output();
+                program.get_last_output().unwrap_or(-1)
+            });
+        for program in programs.iter() {
+            program.check()?;
+        }
+    }
+    programs[4]
+        .get_last_output()
+        .ok_or_else(|| programs[4].unexpected_stop("the last amplifier halted without any output"))
+}
+
+fn iteration_0 (program: &int_code::IntCodeProgram, phase_setting: Vec<i128>) -> Result<i128, DayError> {
+    let mut i = 0;
+    for phase in phase_setting {
+        let mut program = program.clone();
+        program.push_input(phase);
+        program.push_input(i);
+        i = program
+            .run_until_next_output()
+            .ok_or_else(|| program.unexpected_stop("an amplifier halted without any output"))?;
+    }
+    Ok(i)
+}
+
+fn get_all_possible_phase_settings (data: &mut [i128]) -> Vec<Vec<i128>> {
+    let mut permutations = Vec::new();
+    permutohedron::heap_recursive(data, |permutation| {
+        permutations.push(permutation.to_vec())
+    });
+    permutations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_tasks::Part;
+    use crate::examples::{self, Example};
+
+    const EXAMPLES: &[Example] = &[
+        ("3,15,3,16,1002,16,10,16,1,16,15,15,4,15,99,0,0", Part::First, "43210"),
+        ("3,23,3,24,1002,24,10,24,1002,23,-1,23,101,5,23,23,1,24,23,23,4,23,99,0,0", Part::First, "54321"),
+        ("3,31,3,32,1002,32,10,32,1001,31,-2,31,1007,31,0,33,1002,33,7,33,1,33,31,31,1,32,31,31,4,31,99,0,0,0", Part::First, "65210"),
+        ("3,26,1001,26,-4,26,3,27,1002,27,2,27,1,27,26,27,4,27,1001,28,-1,28,1005,28,6,99,0,0,5", Part::Second, "139629729"),
+        ("3,52,1001,52,-5,52,3,53,1,52,56,54,1007,54,5,55,1005,55,26,1001,54,-5,54,1105,1,12,1,53,54,53,1008,54,0,55,1001,55,1,55,2,53,55,53,4,53,1001,56,-1,56,1005,56,6,99,0,0,0,0,10", Part::Second, "18216")
+    ];
+
+    #[test]
+    fn worked_examples () {
+        examples::check(&Day07, EXAMPLES);
+    }
+}
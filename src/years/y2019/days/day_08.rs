@@ -0,0 +1,90 @@
+use crate::answer::Answer;
+use crate::day_error::DayError;
+use crate::day_tasks;
+use crate::input;
+use itertools::Itertools;
+
+pub struct Day08;
+
+impl day_tasks::DayTasks for Day08 {
+    type Input = Vec<u32>;
+
+    fn year (&self) -> String {
+        "2019".to_string()
+    }
+    fn day_number (&self) -> String {
+        "08".to_string()
+    }
+    fn parse (&self, input: &str) -> Result<Vec<u32>, DayError> {
+        input::digits(input)
+    }
+    fn task_0 (&self, pixels: &Vec<u32>) -> Result<Answer, DayError> {
+        pixels
+            .iter()
+            .copied()
+            .chunks(150) // 25 * 6 = 150
+            .into_iter()
+            .map(|chunk| {
+                chunk.fold((0, 0, 0), |(zeros, ones, twos), digit| 
+                    match digit { 
+                        0 => (zeros + 1, ones, twos), 
+                        1 => (zeros, ones + 1, twos), 
+                        2 => (zeros, ones, twos + 1), 
+                        _ => (zeros, ones, twos)})
+            })
+            .min_by_key(|t| t.0)
+            .map(|(_, ones, twos)| Answer::from(ones * twos))
+            .ok_or_else(|| DayError::parse(1, "the image has no layers"))
+    }
+    fn task_1 (&self, pixels: &Vec<u32>) -> Result<Answer, DayError> {
+        let lines = (0..150)
+            .map(|i| {
+                let mut inner_i = i;
+                let mut seeked_digit = 2;
+                while inner_i < pixels.len() && seeked_digit == 2 {
+                    let current_digit = pixels[inner_i];
+                    if current_digit == 0 || current_digit == 1 { seeked_digit = current_digit }
+                    inner_i += 150;
+                }
+                seeked_digit
+            })
+            .chunks(25)
+            .into_iter()
+            .map(|line| line.map(|digit| digit == 1).collect::<Vec<bool>>())
+            .collect::<Vec<Vec<bool>>>();
+        Ok(Answer::Image(lines))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_tasks::Part;
+    use crate::examples::{self, Example};
+
+    // the worked examples use smaller images than the puzzle, these are 25 pixels wide and 6 tall
+    const FRAME: &str = concat!(
+        // a frame in front of a black layer
+        "1111111111111111111111111",
+        "1222222222222222222222221",
+        "1222222222222222222222221",
+        "1222222222222222222222221",
+        "1222222222222222222222221",
+        "1111111111111111111111111",
+        "0000000000000000000000000",
+        "0000000000000000000000000",
+        "0000000000000000000000000",
+        "0000000000000000000000000",
+        "0000000000000000000000000",
+        "0000000000000000000000000");
+
+    const EXAMPLES: &[Example] = &[
+        (FRAME, Part::First, "5336"),
+        (FRAME, Part::Second, "#########################/#.......................#/#.......................#/#.......................#/#.......................#/#########################")
+    ];
+
+    #[test]
+    fn worked_examples () {
+        examples::check(&Day08, EXAMPLES);
+    }
+}
@@ -0,0 +1,54 @@
+use crate::answer::Answer;
+use crate::day_error::DayError;
+use crate::day_tasks;
+use super::int_code;
+
+pub struct Day09;
+
+impl day_tasks::DayTasks for Day09 {
+    type Input = int_code::IntCodeProgram;
+
+    fn year (&self) -> String {
+        "2019".to_string()
+    }
+    fn day_number (&self) -> String {
+        "09".to_string()
+    }
+    fn parse (&self, input: &str) -> Result<int_code::IntCodeProgram, DayError> {
+        int_code::create_program(input)
+    }
+    fn task_0 (&self, program: &int_code::IntCodeProgram) -> Result<Answer, DayError> {
+        task_impl(program, 1)
+    }
+    fn task_1 (&self, program: &int_code::IntCodeProgram) -> Result<Answer, DayError> {
+        task_impl(program, 2)
+    }
+}
+
+fn task_impl (program: &int_code::IntCodeProgram, input_number: i128) -> Result<Answer, DayError> {
+    let mut program = program.clone();
+    program.push_input(input_number);
+    program.run_until_stopped();
+    program
+        .get_last_output()
+        .map(Answer::from)
+        .ok_or_else(|| program.unexpected_stop("the program halted without any output"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_tasks::Part;
+    use crate::examples::{self, Example};
+
+    const EXAMPLES: &[Example] = &[
+        ("109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99", Part::First, "99"),
+        ("1102,34915192,34915192,7,4,7,99,0", Part::First, "1219070632396864"),
+        ("104,1125899906842624,99", Part::First, "1125899906842624")
+    ];
+
+    #[test]
+    fn worked_examples () {
+        examples::check(&Day09, EXAMPLES);
+    }
+}
@@ -0,0 +1,164 @@
+use crate::answer::Answer;
+use crate::day_error::DayError;
+use crate::day_tasks;
+use crate::input;
+use std::collections::HashSet;
+use num_integer::gcd;
+use std::f64::consts::PI;
+
+pub struct Day10;
+
+impl day_tasks::DayTasks for Day10 {
+    type Input = Vec<(i32, i32)>;
+
+    fn year (&self) -> String {
+        "2019".to_string()
+    }
+    fn day_number (&self) -> String {
+        "10".to_string()
+    }
+    fn parse (&self, input: &str) -> Result<Vec<(i32, i32)>, DayError> {
+        Ok(get_asteroid_points(input))
+    }
+    fn task_0 (&self, asteroid_points: &Vec<(i32, i32)>) -> Result<Answer, DayError> {
+        let (_, count) = find_station(asteroid_points)?;
+        Ok(Answer::from(count))
+    }
+    fn task_1 (&self, asteroid_points: &Vec<(i32, i32)>) -> Result<Answer, DayError> {
+        let (station, _) = find_station(asteroid_points)?;
+        let set = get_visible_aseteroids(station, asteroid_points);
+
+        let mut sorted = set
+            .into_iter()
+            .map(|(x, y)| {
+                let angle = (-(y as f64) / ((x * x + y * y) as f64).sqrt()).acos();
+                let angle = if x < 0 { 2.0 * PI - angle } else { angle };
+                (angle, x, y)
+            })
+            .collect::<Vec<(f64, i32, i32)>>();
+        sorted.sort_by(|a, b| a.0.total_cmp(&b.0));
+        sorted
+            .into_iter()
+            .nth(199)
+            .map(|(_, x, y)| {
+                let (x, y) = (x + station.0, y + station.1);
+                Answer::from(x * 100 + y)})
+            .ok_or_else(|| DayError::unsolvable("fewer than 200 asteroids are vaporized in the first rotation"))
+    }
+}
+
+fn get_asteroid_points (input: &str) -> Vec<(i32, i32)> {
+    input::grid(input)
+        .into_iter()
+        .enumerate()
+        .flat_map(|(y, row)| row
+            .into_iter()
+            .enumerate()
+            .filter_map(move |(x, c)| if c == '#' { Some((x as i32, y as i32)) } else { None }))
+        .collect()
+}
+
+/// The asteroid which sees the most other asteroids and how many it sees.
+fn find_station (asteroid_points: &[(i32, i32)]) -> Result<((i32, i32), i32), DayError> {
+    asteroid_points
+        .iter()
+        .map(|position| (*position, count_sightable_asteroids(*position, asteroid_points)))
+        .max_by_key(|(_, count)| *count)
+        .ok_or_else(|| DayError::unsolvable("there are no asteroids on the map"))
+}
+
+fn count_sightable_asteroids (position: (i32, i32), all_asteroid_positions: &[(i32, i32)]) -> i32 {
+    get_visible_aseteroids(position, all_asteroid_positions).len() as i32
+}
+
+fn get_visible_aseteroids (position: (i32, i32), all_asteroid_positions: &[(i32, i32)]) -> HashSet<(i32, i32)> {
+    all_asteroid_positions
+        .iter()
+        .filter(|p| **p != position)
+        .map(|(x, y)| (*x - position.0, *y - position.1))
+        .map(|(x, y)| {
+            if x != 0 && y != 0 {
+                let gcd = gcd(x, y);
+                (x / gcd, y / gcd)
+            }
+            else if x == 0 && y >= 1 {
+                (0, 1)
+            }
+            else if x == 0 && y <= 1 {
+                (0, -1)
+            }
+            else if y == 0 && x >= 1 {
+                (1, 0)
+            }
+            else  {
+                (-1, 0)
+            }
+        })
+        .collect::<HashSet<(i32, i32)>>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_tasks::Part;
+    use crate::examples::{self, Example};
+
+    const EXAMPLES: &[Example] = &[
+        (concat!(
+            ".#..#\n",
+            ".....\n",
+            "#####\n",
+            "....#\n",
+            "...##"),
+            Part::First, "8"),
+        (concat!(
+            ".#..##.###...#######\n",
+            "##.############..##.\n",
+            ".#.######.########.#\n",
+            ".###.#######.####.#.\n",
+            "#####.##.#.##.###.##\n",
+            "..#####..#.#########\n",
+            "####################\n",
+            "#.####....###.#.#.##\n",
+            "##.#################\n",
+            "#####.##.###..####..\n",
+            "..######..##.#######\n",
+            "####.##.####...##..#\n",
+            ".#####..#.######.###\n",
+            "##...#.##########...\n",
+            "#.##########.#######\n",
+            ".####.#.###.###.#.##\n",
+            "....##.##.###..#####\n",
+            ".#.#.###########.###\n",
+            "#.#.#.#####.####.###\n",
+            "###.##.####.##.#..##"),
+            Part::First, "210"),
+        (concat!(
+            ".#..##.###...#######\n",
+            "##.############..##.\n",
+            ".#.######.########.#\n",
+            ".###.#######.####.#.\n",
+            "#####.##.#.##.###.##\n",
+            "..#####..#.#########\n",
+            "####################\n",
+            "#.####....###.#.#.##\n",
+            "##.#################\n",
+            "#####.##.###..####..\n",
+            "..######..##.#######\n",
+            "####.##.####...##..#\n",
+            ".#####..#.######.###\n",
+            "##...#.##########...\n",
+            "#.##########.#######\n",
+            ".####.#.###.###.#.##\n",
+            "....##.##.###..#####\n",
+            ".#.#.###########.###\n",
+            "#.#.#.#####.####.###\n",
+            "###.##.####.##.#..##"),
+            Part::Second, "802")
+    ];
+
+    #[test]
+    fn worked_examples () {
+        examples::check(&Day10, EXAMPLES);
+    }
+}
@@ -1,9 +1,8 @@
+use crate::answer::Answer;
+use crate::day_error::DayError;
 use crate::day_tasks;
 use super::int_code;
 use std::collections::HashSet;
-use colored::Colorize;
-use std::fs;
-use std::time::Instant;
 
 pub struct Day11;
 
@@ -34,16 +33,24 @@ fn step (position: (i32, i32), direction: &Direction) -> (i32, i32) {
 }
 
 impl day_tasks::DayTasks for Day11 {
+    type Input = int_code::IntCodeProgram;
+
+    fn year (&self) -> String {
+        "2019".to_string()
+    }
     fn day_number (&self) -> String {
         "11".to_string()
     }
-    fn task_0 (&self, input: &String) -> String {
-        let (painted_positions, _) = run_painting_robot(input, 0);
+    fn parse (&self, input: &str) -> Result<int_code::IntCodeProgram, DayError> {
+        int_code::create_program(input)
+    }
+    fn task_0 (&self, program: &int_code::IntCodeProgram) -> Result<Answer, DayError> {
+        let (painted_positions, _) = run_painting_robot(program, 0)?;
 
-        painted_positions.len().to_string()
+        Ok(Answer::from(painted_positions.len()))
     }
-    fn task_1 (&self, input: &String) -> String {
-        let (_, white_positions) = run_painting_robot(input, 1);
+    fn task_1 (&self, program: &int_code::IntCodeProgram) -> Result<Answer, DayError> {
+        let (_, white_positions) = run_painting_robot(program, 1)?;
 
         let (min_x, max_x, min_y, max_y) = white_positions
             .iter()
@@ -55,37 +62,19 @@ impl day_tasks::DayTasks for Day11 {
                 (next_min_x, next_max_x, next_min_y, next_max_y)
             });
 
-        for y in (min_y..=max_y).rev() {
-            for x in min_x..=max_x {
-                if white_positions.contains(&(x, y)) { print!("{}", "█".purple()) }
-                else {  print!("{}", "█".cyan()) }
-            }
-            println!("") 
-        }
+        let image = (min_y..=max_y)
+            .rev()
+            .map(|y| (min_x..=max_x).map(|x| white_positions.contains(&(x, y))).collect::<Vec<bool>>())
+            .collect::<Vec<Vec<bool>>>();
 
-        "".to_string()
-    }
-
-    fn run (&self) {
-        let path = format! (".\\Input\\2019\\{}.txt", self.day_number());
-        let contents = fs::read_to_string(path)
-            .expect("Something went wrong reading the input file");
-        println!("Day {}:", self.day_number());
-        let start = Instant::now();
-        let result = self.task_0(&contents);
-        let finish = Instant::now();
-        println!("First Result = {} (took {:?})", result, finish - start);
-        println!("Second Result =");
-        let start = Instant::now();
-        self.task_1(&contents);
-        let finish = Instant::now();
-        println!("(took {:?})", finish - start);
-    
+        Ok(Answer::Image(image))
     }
 }
 
-fn run_painting_robot (input: &String, first_input: i128) -> (HashSet<(i32, i32)>, HashSet<(i32, i32)>) {
-    let mut program = int_code::create_program(input);
+type Positions = HashSet<(i32, i32)>;
+
+fn run_painting_robot (program: &int_code::IntCodeProgram, first_input: i128) -> Result<(Positions, Positions), DayError> {
+    let mut program = program.clone();
     let mut painted_positions: HashSet<(i32, i32)> = HashSet::new();
     let mut currently_white_positions: HashSet<(i32, i32)> = HashSet::new();
 
@@ -111,6 +100,29 @@ fn run_painting_robot (input: &String, first_input: i128) -> (HashSet<(i32, i32)
         program.push_input(program_input);
         program.run_until_next_output();
     }
+    program.check()?;
     
-    (painted_positions, currently_white_positions)
+    Ok((painted_positions, currently_white_positions))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_tasks::Part;
+    use crate::examples::{self, Example};
+
+    // a robot that ignores the camera and paints like the worked example, each pair is IN -> [100], OUT #color, OUT #turn
+    const ROBOT: &str = "\
+        3,100,104,1,104,0,3,100,104,0,104,0,3,100,104,1,104,0,3,100,104,1,104,0,\
+        3,100,104,0,104,1,3,100,104,1,104,0,3,100,104,1,104,0,99";
+
+    const EXAMPLES: &[Example] = &[
+        (ROBOT, Part::First, "6"),
+        (ROBOT, Part::Second, "..#/..#/##.")
+    ];
+
+    #[test]
+    fn worked_examples () {
+        examples::check(&Day11, EXAMPLES);
+    }
 }
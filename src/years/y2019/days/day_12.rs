@@ -1,4 +1,7 @@
+use crate::answer::Answer;
+use crate::day_error::DayError;
 use crate::day_tasks;
+use crate::input;
 use lazy_static::lazy_static;
 use regex::Regex;
 use std::collections::HashSet;
@@ -7,27 +10,22 @@ use num_integer::lcm;
 pub struct Day12;
 
 impl day_tasks::DayTasks for Day12 {
+    type Input = Vec<Moon>;
+
+    fn year (&self) -> String {
+        "2019".to_string()
+    }
     fn day_number (&self) -> String {
         "12".to_string()
     }
-    fn task_0 (&self, input: &String) -> String {
-        let moons = input
-            .lines()
-            .filter_map(parse_to_moon)
-            .collect::<Vec<Moon>>();
-        let mut moons = Moons { moons: moons};
-        for _ in 0..1_000 {
-            moons.apply_gravity();
-            moons.apply_velocity();
-        }
-        moons.calculate_energy().to_string()
-    }
-    fn task_1 (&self, input: &String) -> String {
-        let moons = input
-            .lines()
-            .filter_map(parse_to_moon)
-            .collect::<Vec<Moon>>();
-        let mut moons = Moons { moons: moons};
+    fn parse (&self, input: &str) -> Result<Vec<Moon>, DayError> {
+        parse_moons(input)
+    }
+    fn task_0 (&self, moons: &Vec<Moon>) -> Result<Answer, DayError> {
+        Ok(Answer::from(energy_after(moons, 1_000)))
+    }
+    fn task_1 (&self, moons: &Vec<Moon>) -> Result<Answer, DayError> {
+        let mut moons = Moons { moons: moons.clone() };
         while !moons.check_for_sequence() {
             moons.apply_gravity();
             moons.apply_velocity();
@@ -37,10 +35,23 @@ impl day_tasks::DayTasks for Day12 {
         let mut set = rates.iter().map(|i| *i as i128).collect::<HashSet<i128>>();
         lowest_common_multiple(&mut set);
         
-        set.iter().next().map(|i| i.to_string()).unwrap_or("- Something went wrong -".to_string())
+        set
+            .iter()
+            .next()
+            .map(|i| Answer::from(*i))
+            .ok_or_else(|| DayError::unsolvable("the moons never repeat"))
     }
 }
 
+fn energy_after (moons: &[Moon], steps: usize) -> i32 {
+    let mut moons = Moons { moons: moons.to_vec() };
+    for _ in 0..steps {
+        moons.apply_gravity();
+        moons.apply_velocity();
+    }
+    moons.calculate_energy()
+}
+
 fn lowest_common_multiple (set: &mut HashSet<i128>) {
     while set.len() != 1 {
         *set = set
@@ -65,7 +76,7 @@ impl Moons {
         let for_other = self.moons.to_vec();
         for moon in &mut self.moons {
             for other in &for_other {
-                moon.apply_gravity(&other);
+                moon.apply_gravity(other);
             }
         }
     }
@@ -96,7 +107,7 @@ impl Moons {
 }
 
 #[derive(Clone)]
-struct Moon {
+pub struct Moon {
     position: (i32, i32, i32),
     velocity: (i32, i32, i32),
     x_sequence: Vec<i32>,
@@ -134,7 +145,7 @@ impl Moon {
         fn check (new_value: i32, sequence: &mut Vec<i32>, rate: &mut Option<i32>) {
             if rate.is_none() {
                 sequence.push(new_value);
-                if sequence.len() % 2 == 0
+                if sequence.len().is_multiple_of(2)
                 {
                     let first_half = &sequence[0..(sequence.len()/2)];
                     let second_half = &sequence[(sequence.len()/2)..sequence.len()];
@@ -169,26 +180,26 @@ impl Moon {
     }
 }
 
+fn parse_moons (input: &str) -> Result<Vec<Moon>, DayError> {
+    let moons = input::lines(input)
+        .map(|(line_number, line)| parse_to_moon(line)
+            .ok_or_else(|| DayError::parse(line_number, format!("'{}' is not a moon like <x=1, y=2, z=3>", line))))
+        .collect::<Result<Vec<Moon>, DayError>>()?;
+    if moons.is_empty() { Err(DayError::parse(1, "there are no moons")) }
+    else { Ok(moons) }
+}
+
 fn parse_to_moon(text: &str) -> Option<Moon> {
     lazy_static! {
         static ref RE: Regex = Regex::new("<x=(.*), y=(.*), z=(.*)>").unwrap();
     }
     RE
         .captures(text)
-        .map(|captures| {
-            let x = captures
-                .get(1)
-                .map(|text_x| text_x.as_str().parse::<i32>().unwrap_or(0))
-                .unwrap();
-            let y = captures
-                .get(2)
-                .map(|text_y| text_y.as_str().parse::<i32>().unwrap_or(0))
-                .unwrap();
-            let z = captures
-                .get(3)
-                .map(|text_z| text_z.as_str().parse::<i32>().unwrap_or(0))
-                .unwrap();
-            Moon{
+        .and_then(|captures| {
+            let x = captures.get(1)?.as_str().parse::<i32>().ok()?;
+            let y = captures.get(2)?.as_str().parse::<i32>().ok()?;
+            let z = captures.get(3)?.as_str().parse::<i32>().ok()?;
+            Some(Moon{
                 position: (x, y, z), 
                 velocity: (0, 0, 0),
                 x_sequence: Vec::new(),
@@ -197,6 +208,40 @@ fn parse_to_moon(text: &str) -> Option<Moon> {
                 x_rate: None,
                 y_rate: None,
                 z_rate: None
-            }
+            })
         })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_tasks::Part;
+    use crate::examples::{self, Example};
+
+    const EXAMPLES: &[Example] = &[
+        (concat!(
+            "<x=-1, y=0, z=2>\n",
+            "<x=2, y=-10, z=-7>\n",
+            "<x=4, y=-8, z=8>\n",
+            "<x=3, y=5, z=-1>"),
+            Part::Second, "2772"),
+        (concat!(
+            "<x=-8, y=-10, z=0>\n",
+            "<x=5, y=5, z=10>\n",
+            "<x=2, y=-7, z=3>\n",
+            "<x=9, y=-8, z=-3>"),
+            Part::Second, "4686774924")
+    ];
+
+    #[test]
+    fn worked_examples () {
+        examples::check(&Day12, EXAMPLES);
+    }
+
+    // the part 1 examples simulate fewer steps than the puzzle
+    #[test]
+    fn energy_after_steps () {
+        assert_eq!(energy_after(&parse_moons(EXAMPLES[0].0).unwrap(), 10), 179);
+        assert_eq!(energy_after(&parse_moons(EXAMPLES[1].0).unwrap(), 100), 1940);
+    }
+}
@@ -0,0 +1,109 @@
+use crate::answer::Answer;
+use crate::day_error::DayError;
+use crate::day_tasks;
+use super::int_code::{create_program, IntCodeProgram, IntCodeProgramStatus::Ready};
+
+pub struct Day13;
+
+impl day_tasks::DayTasks for Day13 {
+    type Input = IntCodeProgram;
+
+    fn year (&self) -> String {
+        "2019".to_string()
+    }
+    fn day_number (&self) -> String {
+        "13".to_string()
+    }
+    fn parse (&self, input: &str) -> Result<IntCodeProgram, DayError> {
+        create_program(input)
+    }
+    fn task_0 (&self, program: &IntCodeProgram) -> Result<Answer, DayError> {
+        let mut program = program.clone();
+        
+        let mut i = 0;
+
+        while program.get_status() == Ready {
+            program.run_until_next_output().unwrap_or(-1);
+            program.run_until_next_output().unwrap_or(-1);
+            if program.run_until_next_output().unwrap_or(-1) == 2 {
+                i += 1;
+            }
+        }
+        program.check()?;
+        
+        Ok(Answer::from(i))
+    }
+    fn task_1 (&self, program: &IntCodeProgram) -> Result<Answer, DayError> {
+        let mut program = program.clone();
+        program.day_13_part_two_initialize()?;
+
+        let mut last_known_paddle_x = 0;
+
+        let mut last_known_score = 0;
+
+        while program.get_status() == Ready {
+            let x = program.run_until_next_output().unwrap_or(-1);
+            let y = program.run_until_next_output().unwrap_or(-1);
+            let tile_id = program.run_until_next_output().unwrap_or(-1);
+            if tile_id == 3 {
+                last_known_paddle_x = x;
+            }
+            if tile_id == 4 {
+                let next_input = if x < last_known_paddle_x { -1 } else if x > last_known_paddle_x { 1 } else { 0 };
+                program.push_input(next_input);
+            }
+            if x == -1 && y == 0 {
+                last_known_score = tile_id;
+            }
+        }
+        program.check()?;
+
+        Ok(Answer::from(last_known_score))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answer::Answer;
+    use crate::assembler;
+    use crate::day_tasks::{DayTasks, Part};
+
+    // draws two blocks and a wall, with free play also a paddle left of the ball, and scores 1000 plus the joystick
+    const GAME: &str = "
+        ADD [0], [0] -> [free_play]     ; 2, or 4 once free play put a 2 into cell 0
+        OUT #0
+        OUT #0
+        OUT #2
+        OUT #1
+        OUT #0
+        OUT #2
+        OUT #2
+        OUT #0
+        OUT #1
+        EQ [free_play], #2 -> [free_play]
+        JNZ [free_play], end
+        OUT #3
+        OUT #5
+        OUT #3
+        OUT #4
+        OUT #4
+        OUT #4
+        IN -> [joystick]
+        ADD [joystick], #1000 -> [score]
+        OUT #-1
+        OUT #0
+        OUT [score]
+end:    HLT
+free_play: .data 0
+joystick:  .data 0
+score:     .data 0
+";
+
+    #[test]
+    fn counts_blocks_and_follows_the_ball () {
+        let program = Day13.parse(&assembler::assemble(GAME).unwrap()).unwrap();
+        assert_eq!(Day13.task(Part::First, &program).unwrap(), Answer::from(2));
+        assert_eq!(Day13.task(Part::Second, &program).unwrap(), Answer::from(1001));
+    }
+}
@@ -0,0 +1,256 @@
+use crate::answer::Answer;
+use crate::day_error::DayError;
+use crate::day_tasks;
+use crate::input;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::HashMap;
+
+type Chemical = (String, i64);
+type Recipes = HashMap<String, (Vec<Chemical>, i64)>;
+
+pub struct Day14;
+
+impl day_tasks::DayTasks for Day14 {
+    type Input = Recipes;
+
+    fn year (&self) -> String {
+        "2019".to_string()
+    }
+    fn day_number (&self) -> String {
+        "14".to_string()
+    }
+    fn parse (&self, input: &str) -> Result<Recipes, DayError> {
+        parse_recipes(input)
+    }
+    fn task_0 (&self, recipes: &Recipes) -> Result<Answer, DayError> {
+        let mut available_elements: HashMap<String, i64> = HashMap::new();
+
+        let required_ore_for_one_fuel = get_required_ore("FUEL".to_string(), 1, 0, &mut available_elements, recipes);
+
+        Ok(Answer::from(required_ore_for_one_fuel))
+    }
+    fn task_1 (&self, recipes: &Recipes) -> Result<Answer, DayError> {
+        let ore_amount = 1_000_000_000_000i64;
+        let required_ore = |fuel: i64| get_required_ore("FUEL".to_string(), fuel, 0, &mut HashMap::new(), recipes);
+
+        // the required ore grows with the fuel, so the largest affordable amount can be bisected
+        let mut lower = 0;
+        let mut upper = 1;
+        while required_ore(upper) <= ore_amount {
+            lower = upper;
+            upper *= 2;
+        }
+        while upper - lower > 1 {
+            let middle = (lower + upper) / 2;
+            if required_ore(middle) <= ore_amount { lower = middle } else { upper = middle }
+        }
+
+        Ok(Answer::from(lower))
+    }
+}
+
+fn get_required_ore (
+    element: String, 
+    amount: i64, 
+    current_ore_amount: i64,
+    available_elements: &mut HashMap<String, i64>,
+    recipes: &Recipes) -> i64 {
+    if element == "ORE" { 
+        current_ore_amount + amount }
+    else {
+        let mut required_amount = amount;
+        if available_elements.contains_key(&element) {
+            let available_amount = available_elements[&element];
+            if available_amount >= required_amount {
+                available_elements.remove(&element);
+                if available_amount > required_amount {
+                    available_elements.insert(element, available_amount - required_amount);
+                }
+                return current_ore_amount;
+            }
+            else {
+                available_elements.remove(&element);
+                required_amount -= available_amount;
+            }
+        }
+
+        let (ingredients, recipe_amount) = &recipes[&element];
+        let factor = if required_amount % recipe_amount == 0 { required_amount / recipe_amount } else { required_amount / recipe_amount + 1 };
+        let remainding_amount = if required_amount % recipe_amount == 0 { 0 } else { recipe_amount - required_amount % recipe_amount };
+        if remainding_amount > 0 {
+            available_elements.insert(element, remainding_amount);
+        }
+        ingredients
+            .iter()
+            .fold(current_ore_amount, |current_ore, (next_element_name, next_element_amount)|
+                get_required_ore(
+                    next_element_name.to_string(), 
+                    next_element_amount * factor, 
+                    current_ore_amount, 
+                    available_elements,
+                    recipes) + current_ore
+            )
+    }
+}
+
+fn parse_recipes (input: &str) -> Result<Recipes, DayError> {
+    let recipes = input::lines(input)
+        .map(|(line_number, line)| parse_to_recipe(line)
+            .ok_or_else(|| DayError::parse(line_number, format!("'{}' is not a reaction like 7 A, 1 B => 1 C", line))))
+        .collect::<Result<Recipes, DayError>>()?;
+    if !recipes.contains_key("FUEL") {
+        return Err(DayError::unsolvable("no reaction produces FUEL"));
+    }
+    let unknown = recipes
+        .values()
+        .flat_map(|(ingredients, _)| ingredients.iter())
+        .find(|(name, _)| name != "ORE" && !recipes.contains_key(name));
+    match unknown {
+        Some((name, _)) => Err(DayError::unsolvable(format!("no reaction produces {}", name))),
+        None => Ok(recipes)
+    }
+}
+
+fn parse_to_recipe(line: &str) -> Option<(String, (Vec<Chemical>, i64))> {
+    lazy_static! {
+        static ref RE_0: Regex = Regex::new("^(.*) => (.*)$").unwrap();
+        static ref RE_1: Regex = Regex::new("^(\\d+) ([A-Z]+)$").unwrap();
+    }
+    let parse_chemical = |text: &str| RE_1
+        .captures(text.trim())
+        .and_then(|captures| {
+            let amount = captures.get(1)?.as_str().parse::<i64>().ok()?;
+            let name = captures.get(2)?.as_str();
+            Some((name.to_string(), amount))
+        });
+    let captures = RE_0.captures(line.trim())?;
+    let ingredients = captures
+        .get(1)?
+        .as_str()
+        .split(", ")
+        .map(parse_chemical)
+        .collect::<Option<Vec<Chemical>>>()?;
+    let (name, amount) = parse_chemical(captures.get(2)?.as_str())?;
+    Some((name, (ingredients, amount)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_tasks::Part;
+    use crate::examples::{self, Example};
+
+    const EXAMPLES: &[Example] = &[
+        (concat!(
+            "10 ORE => 10 A\n",
+            "1 ORE => 1 B\n",
+            "7 A, 1 B => 1 C\n",
+            "7 A, 1 C => 1 D\n",
+            "7 A, 1 D => 1 E\n",
+            "7 A, 1 E => 1 FUEL"),
+            Part::First, "31"),
+        (concat!(
+            "9 ORE => 2 A\n",
+            "8 ORE => 3 B\n",
+            "7 ORE => 5 C\n",
+            "3 A, 4 B => 1 AB\n",
+            "5 B, 7 C => 1 BC\n",
+            "4 C, 1 A => 1 CA\n",
+            "2 AB, 3 BC, 4 CA => 1 FUEL"),
+            Part::First, "165"),
+        (concat!(
+            "157 ORE => 5 NZVS\n",
+            "165 ORE => 6 DCFZ\n",
+            "44 XJWVT, 5 KHKGT, 1 QDVJ, 29 NZVS, 9 GPVTF, 48 HKGWZ => 1 FUEL\n",
+            "12 HKGWZ, 1 GPVTF, 8 PSHF => 9 QDVJ\n",
+            "179 ORE => 7 PSHF\n",
+            "177 ORE => 5 HKGWZ\n",
+            "7 DCFZ, 7 PSHF => 2 XJWVT\n",
+            "165 ORE => 2 GPVTF\n",
+            "3 DCFZ, 7 NZVS, 5 HKGWZ, 10 PSHF => 8 KHKGT"),
+            Part::First, "13312"),
+        (concat!(
+            "2 VPVL, 7 FWMGM, 2 CXFTF, 11 MNCFX => 1 STKFG\n",
+            "17 NVRVD, 3 JNWZP => 8 VPVL\n",
+            "53 STKFG, 6 MNCFX, 46 VJHF, 81 HVMC, 68 CXFTF, 25 GNMV => 1 FUEL\n",
+            "22 VJHF, 37 MNCFX => 5 FWMGM\n",
+            "139 ORE => 4 NVRVD\n",
+            "144 ORE => 7 JNWZP\n",
+            "5 MNCFX, 7 RFSQX, 2 FWMGM, 2 VPVL, 19 CXFTF => 3 HVMC\n",
+            "5 VJHF, 7 MNCFX, 9 VPVL, 37 CXFTF => 6 GNMV\n",
+            "145 ORE => 6 MNCFX\n",
+            "1 NVRVD => 8 CXFTF\n",
+            "1 VJHF, 6 MNCFX => 4 RFSQX\n",
+            "176 ORE => 6 VJHF"),
+            Part::First, "180697"),
+        (concat!(
+            "171 ORE => 8 CNZTR\n",
+            "7 ZLQW, 3 BMBT, 9 XCVML, 26 XMNCP, 1 WPTQ, 2 MZWV, 1 RJRHP => 4 PLWSL\n",
+            "114 ORE => 4 BHXH\n",
+            "14 VRPVC => 6 BMBT\n",
+            "6 BHXH, 18 KTJDG, 12 WPTQ, 7 PLWSL, 31 FHTLT, 37 ZDVW => 1 FUEL\n",
+            "6 WPTQ, 2 BMBT, 8 ZLQW, 18 KTJDG, 1 XMNCP, 6 MZWV, 1 RJRHP => 6 FHTLT\n",
+            "15 XDBXC, 2 LTCX, 1 VRPVC => 6 ZLQW\n",
+            "13 WPTQ, 10 LTCX, 3 RJRHP, 14 XMNCP, 2 MZWV, 1 ZLQW => 1 ZDVW\n",
+            "5 BMBT => 4 WPTQ\n",
+            "189 ORE => 9 KTJDG\n",
+            "1 MZWV, 17 XDBXC, 3 XCVML => 2 XMNCP\n",
+            "12 VRPVC, 27 CNZTR => 2 XDBXC\n",
+            "15 KTJDG, 12 BHXH => 5 XCVML\n",
+            "3 BHXH, 2 VRPVC => 7 MZWV\n",
+            "121 ORE => 7 VRPVC\n",
+            "7 XCVML => 6 RJRHP\n",
+            "5 BHXH, 4 VRPVC => 5 LTCX"),
+            Part::First, "2210736"),
+        (concat!(
+            "157 ORE => 5 NZVS\n",
+            "165 ORE => 6 DCFZ\n",
+            "44 XJWVT, 5 KHKGT, 1 QDVJ, 29 NZVS, 9 GPVTF, 48 HKGWZ => 1 FUEL\n",
+            "12 HKGWZ, 1 GPVTF, 8 PSHF => 9 QDVJ\n",
+            "179 ORE => 7 PSHF\n",
+            "177 ORE => 5 HKGWZ\n",
+            "7 DCFZ, 7 PSHF => 2 XJWVT\n",
+            "165 ORE => 2 GPVTF\n",
+            "3 DCFZ, 7 NZVS, 5 HKGWZ, 10 PSHF => 8 KHKGT"),
+            Part::Second, "82892753"),
+        (concat!(
+            "2 VPVL, 7 FWMGM, 2 CXFTF, 11 MNCFX => 1 STKFG\n",
+            "17 NVRVD, 3 JNWZP => 8 VPVL\n",
+            "53 STKFG, 6 MNCFX, 46 VJHF, 81 HVMC, 68 CXFTF, 25 GNMV => 1 FUEL\n",
+            "22 VJHF, 37 MNCFX => 5 FWMGM\n",
+            "139 ORE => 4 NVRVD\n",
+            "144 ORE => 7 JNWZP\n",
+            "5 MNCFX, 7 RFSQX, 2 FWMGM, 2 VPVL, 19 CXFTF => 3 HVMC\n",
+            "5 VJHF, 7 MNCFX, 9 VPVL, 37 CXFTF => 6 GNMV\n",
+            "145 ORE => 6 MNCFX\n",
+            "1 NVRVD => 8 CXFTF\n",
+            "1 VJHF, 6 MNCFX => 4 RFSQX\n",
+            "176 ORE => 6 VJHF"),
+            Part::Second, "5586022"),
+        (concat!(
+            "171 ORE => 8 CNZTR\n",
+            "7 ZLQW, 3 BMBT, 9 XCVML, 26 XMNCP, 1 WPTQ, 2 MZWV, 1 RJRHP => 4 PLWSL\n",
+            "114 ORE => 4 BHXH\n",
+            "14 VRPVC => 6 BMBT\n",
+            "6 BHXH, 18 KTJDG, 12 WPTQ, 7 PLWSL, 31 FHTLT, 37 ZDVW => 1 FUEL\n",
+            "6 WPTQ, 2 BMBT, 8 ZLQW, 18 KTJDG, 1 XMNCP, 6 MZWV, 1 RJRHP => 6 FHTLT\n",
+            "15 XDBXC, 2 LTCX, 1 VRPVC => 6 ZLQW\n",
+            "13 WPTQ, 10 LTCX, 3 RJRHP, 14 XMNCP, 2 MZWV, 1 ZLQW => 1 ZDVW\n",
+            "5 BMBT => 4 WPTQ\n",
+            "189 ORE => 9 KTJDG\n",
+            "1 MZWV, 17 XDBXC, 3 XCVML => 2 XMNCP\n",
+            "12 VRPVC, 27 CNZTR => 2 XDBXC\n",
+            "15 KTJDG, 12 BHXH => 5 XCVML\n",
+            "3 BHXH, 2 VRPVC => 7 MZWV\n",
+            "121 ORE => 7 VRPVC\n",
+            "7 XCVML => 6 RJRHP\n",
+            "5 BHXH, 4 VRPVC => 5 LTCX"),
+            Part::Second, "460664")
+    ];
+
+    #[test]
+    fn worked_examples () {
+        examples::check(&Day14, EXAMPLES);
+    }
+}
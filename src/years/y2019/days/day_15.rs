@@ -0,0 +1,161 @@
+use crate::answer::Answer;
+use crate::day_error::DayError;
+use crate::day_tasks;
+use super::int_code;
+use std::collections::{HashMap, VecDeque, HashSet};
+
+/// Status the droid reported for every explored position, 0 being a wall and 2 the oxygen system.
+type Map = HashMap<(i32, i32), i128>;
+
+pub struct Day15;
+
+impl day_tasks::DayTasks for Day15 {
+    type Input = Map;
+
+    fn year (&self) -> String {
+        "2019".to_string()
+    }
+    fn day_number (&self) -> String {
+        "15".to_string()
+    }
+    fn parse (&self, input: &str) -> Result<Map, DayError> {
+        let mut program = int_code::create_program(input)?;
+        let mut map: Map = HashMap::new();
+        map.insert((0, 0), 1);
+        explore_map(&mut program, &mut map)?;
+        Ok(map)
+    }
+    fn task_0 (&self, map: &Map) -> Result<Answer, DayError> {
+        get_length_of_shortest_path_to_oxygen_system(map)
+            .map(|(_, i)| Answer::from(i))
+            .ok_or_else(|| DayError::unsolvable("the droid didn't find the oxygen system"))
+    }
+    fn task_1 (&self, map: &Map) -> Result<Answer, DayError> {
+        get_length_of_shortest_path_to_oxygen_system(map)
+            .map(|(start, _)| Answer::from(fill_room_with_oxygen(start, map)))
+            .ok_or_else(|| DayError::unsolvable("the droid didn't find the oxygen system"))
+    }
+}
+
+fn fill_room_with_oxygen (start: (i32, i32), map: &Map) -> i32 {
+    let mut queue: VecDeque<((i32, i32), i32)> = VecDeque::new();
+    let mut visited_positions: HashSet<(i32, i32)> = HashSet::new();
+    let mut max_minutes = 0;
+    queue.push_back((start, 0));
+    while let Some((current_position, distance_sofar)) = queue.pop_front() {
+        visited_positions.insert(current_position);
+        let (x, y) = current_position;
+        let next_distance_sofar = distance_sofar + 1;
+        for next_point in [(x, y - 1), (x, y + 1), (x - 1, y), (x + 1, y)]
+            .iter()
+            .filter(|next_point| map.get(next_point).is_some_and(|status| *status != 0)
+                && !visited_positions.contains(next_point)) {
+            queue.push_back((*next_point, next_distance_sofar));
+            if next_distance_sofar > max_minutes {
+                max_minutes = next_distance_sofar;
+            }
+        }
+    };
+    max_minutes
+}
+
+fn get_length_of_shortest_path_to_oxygen_system (map: &Map) -> Option<((i32, i32), i32)> {
+    let mut queue: VecDeque<((i32, i32), i32)> = VecDeque::new();
+    let mut visited_positions: HashSet<(i32, i32)> = HashSet::new();
+    queue.push_back(((0, 0), 0));
+    while let Some((current_position, distance_sofar)) = queue.pop_front() {
+        visited_positions.insert(current_position);
+        let (x, y) = current_position;
+        let next_distance_sofar = distance_sofar + 1;
+        for next_point in [(x, y - 1), (x, y + 1), (x - 1, y), (x + 1, y)]
+            .iter()
+            .filter(|next_point| map.get(next_point).is_some_and(|status| *status != 0)
+                && !visited_positions.contains(next_point)) {
+            if let Some(2) = map.get(next_point) {
+                return Some((*next_point, next_distance_sofar));
+            }
+            queue.push_back((*next_point, next_distance_sofar));
+        }
+    };
+    None
+}
+
+fn explore_map (program: &mut int_code::IntCodeProgram, map: &mut Map) -> Result<(), DayError> {
+    let mut stack: VecDeque<((i32, i32), i128)> = VecDeque::new();
+    stack.push_front(((0, 0), 1));
+    while let Some((current_point, return_command)) = stack.front() {
+        let (x, y) = *current_point;
+        let next_point_maybe = (1..=4).map(|direction| (match direction {
+                1 => (x, y - 1),
+                2 => (x, y + 1),
+                3 => (x - 1, y),
+                4 => (x + 1, y),
+                _ => (x, y)
+            }, direction))
+            .find(|(next_point, _)| !map.contains_key(next_point));
+        if let Some((next_point, direction)) = next_point_maybe {
+            program.push_input(direction);
+            let status = program
+                .run_until_next_output()
+                .ok_or_else(|| program.unexpected_stop("the droid halted before reporting its status"))?;
+            map.insert(next_point, status);
+            if status != 0 {
+                let next_return_command = match direction {
+                    1 => 2,
+                    2 => 1,
+                    3 => 4,
+                    4 => 3,
+                    _ => direction
+                };
+                stack.push_front((next_point, next_return_command));
+            }
+        }
+        else {
+            program.push_input(*return_command);
+            program
+                .run_until_next_output()
+                .ok_or_else(|| program.unexpected_stop("the droid halted before reporting its status"))?;
+            stack.pop_front();
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the droid's exploration needs a maze program, the searches are checked on the worked example's room
+    const ROOM: &str = "
+ ##
+#..##
+#.#D.#
+#.O.#
+ ###";
+
+    /// The statuses of a drawn map, positioned so the droid `D` is at the origin.
+    fn map (text: &str) -> Map {
+        let cells = text
+            .lines()
+            .enumerate()
+            .flat_map(|(y, line)| line.chars().enumerate().map(move |(x, c)| ((x as i32, y as i32), c)))
+            .collect::<Vec<((i32, i32), char)>>();
+        let (droid_x, droid_y) = cells.iter().find(|(_, c)| *c == 'D').map(|(position, _)| *position).unwrap();
+        cells
+            .iter()
+            .filter_map(|((x, y), c)| match c {
+                '#' => Some(0),
+                '.' | 'D' => Some(1),
+                'O' => Some(2),
+                _ => None
+            }.map(|status| ((x - droid_x, y - droid_y), status)))
+            .collect()
+    }
+
+    #[test]
+    fn finds_the_oxygen_system_and_fills_the_room () {
+        let room = map(ROOM);
+        assert_eq!(get_length_of_shortest_path_to_oxygen_system(&room), Some(((-1, 1), 2)));
+        assert_eq!(fill_room_with_oxygen((-1, 1), &room), 4);
+    }
+}
@@ -0,0 +1,111 @@
+use crate::answer::Answer;
+use crate::day_error::DayError;
+use crate::day_tasks;
+use crate::input;
+use std::collections::HashMap;
+
+pub struct Day16;
+
+impl day_tasks::DayTasks for Day16 {
+    type Input = Vec<i32>;
+
+    fn year (&self) -> String {
+        "2019".to_string()
+    }
+    fn day_number (&self) -> String {
+        "16".to_string()
+    }
+    fn parse (&self, input: &str) -> Result<Vec<i32>, DayError> {
+        parse_digits(input)
+    }
+    fn task_0 (&self, digits: &Vec<i32>) -> Result<Answer, DayError> {
+        let mut cache: HashMap<(usize, i32), i32> = HashMap::new();
+        Ok(Answer::from((0..8).map(|i| get_digity(i, 100, digits, &mut cache, digits.len())).fold("".to_string(), |current, next| format!("{}{}", current, next))))
+    }
+    fn task_1 (&self, digits: &Vec<i32>) -> Result<Answer, DayError> {
+        let len = digits.len() * 10_000;
+        let offset = digits.iter().take(7).fold(0, |current, next| current * 10 + *next as usize);
+        if offset < len / 2 || offset + 8 > len {
+            return Err(DayError::unsolvable(format!("the message offset {} isn't in the second half of the signal", offset)));
+        }
+        let mut digits = digits.iter().copied().cycle().skip(offset % digits.len()).take(len - offset).collect::<Vec<_>>();
+        
+        for _ in 0..100 {
+            digits = generate_numbers(digits);
+        }
+
+        Ok(Answer::from(digits.iter().take(8).fold("".to_string(), |current, next| format!("{}{}", current, next))))
+    }
+}
+
+fn parse_digits (input: &str) -> Result<Vec<i32>, DayError> {
+    let digits = input::digits(input)?
+        .into_iter()
+        .map(|digit| digit as i32)
+        .collect::<Vec<i32>>();
+    if digits.len() < 8 { Err(DayError::parse(1, "the signal has less than 8 digits")) }
+    else { Ok(digits) }
+}
+
+fn get_digity (index: usize, iteration: i32, signal: &[i32], cache: &mut HashMap<(usize, i32), i32>, length: usize) -> i32 {
+    if iteration == 0 {
+        signal[index % signal.len()]
+    }
+    else if let Some(digit) = cache.get(&(index, iteration)) {
+        *digit
+    }
+    else {
+        let new_iteration = iteration - 1;
+        let small_step = index + 1;
+        let big_step = small_step * 4;
+        let plus: i32 = ((small_step - 1)..length)
+            .step_by(big_step)
+            .flat_map(|start| (start..length).take(small_step))
+            .map(|i| get_digity(i, new_iteration, signal, cache, length))
+            .sum();
+        let minus: i32 = ((small_step * 3 - 1)..length)
+            .step_by(big_step)
+            .flat_map(|start| (start..length).take(small_step))
+            .map(|i| get_digity(i, new_iteration, signal, cache, length))
+            .sum();
+        let number = plus - minus;
+        let digit = number.abs() % 10;
+        cache.insert((index, iteration), digit);
+        digit
+    }
+}
+
+fn generate_numbers (mut input: Vec<i32>) -> Vec<i32> {
+    input
+        .reverse();
+    let mut output = input.iter()
+        .scan(0, |current, next| {
+            *current += next;
+            Some(*current)
+        })
+        .map(|i| i.abs() % 10)
+        .collect::<Vec<i32>>();
+    output.reverse();
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_tasks::Part;
+    use crate::examples::{self, Example};
+
+    const EXAMPLES: &[Example] = &[
+        ("80871224585914546619083218645595", Part::First, "24176176"),
+        ("19617804207202209144916044189917", Part::First, "73745418"),
+        ("69317163492948606335995924319873", Part::First, "52432133"),
+        ("03036732577212944063491565474664", Part::Second, "84462026"),
+        ("02935109699940807407585447034323", Part::Second, "78725270"),
+        ("03081770884921959731165446850517", Part::Second, "53553731")
+    ];
+
+    #[test]
+    fn worked_examples () {
+        examples::check(&Day16, EXAMPLES);
+    }
+}
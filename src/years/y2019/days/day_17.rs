@@ -1,34 +1,57 @@
+use crate::answer::Answer;
+use crate::day_error::DayError;
 use crate::day_tasks;
 use super::int_code;
 use std::collections::HashSet;
 
+type Scaffolds = HashSet<(i128, i128)>;
+type MovementFunction = Vec<i32>;
+
 pub struct Day17;
 
+/// The camera view the ASCII program prints before the robot is woken up.
+pub struct Camera {
+    program: int_code::IntCodeProgram,
+    scaffolds: Scaffolds,
+    robot_position: (i128, i128),
+    robot_facing_direction: i32
+}
+
 impl day_tasks::DayTasks for Day17 {
+    type Input = Camera;
+
+    fn year (&self) -> String {
+        "2019".to_string()
+    }
     fn day_number (&self) -> String {
         "17".to_string()
     }
-    fn task_0 (&self, input: &String) -> String {
-        let (scaffolds, _, _) = get_map_and_robot_status(input);
+    fn parse (&self, input: &str) -> Result<Camera, DayError> {
+        let program = int_code::create_program(input)?;
+        let (scaffolds, robot_position, robot_facing_direction) = get_map_and_robot_status(&program)?;
+        Ok(Camera { program, scaffolds, robot_position, robot_facing_direction })
+    }
+    fn task_0 (&self, camera: &Camera) -> Result<Answer, DayError> {
+        let scaffolds = &camera.scaffolds;
 
-        scaffolds
+        Ok(scaffolds
             .iter()
             .filter(|(x, y)| scaffolds.contains(&(*x + 1, *y))
                 && scaffolds.contains(&(*x - 1, *y)) 
                 && scaffolds.contains(&(*x, *y + 1)) 
                 && scaffolds.contains(&(*x, *y - 1)))
             .map(|(x,y)| (*x)*(*y))
-            .sum::<i128>().to_string()
+            .sum::<i128>()
+            .into())
     }
-    fn task_1 (&self, input: &String) -> String {
-        let (scaffolds, robot_position, robot_facing_direction) = get_map_and_robot_status(input);
+    fn task_1 (&self, camera: &Camera) -> Result<Answer, DayError> {
+        let input_sequence = get_input_sequence(camera.robot_position, camera.robot_facing_direction, &camera.scaffolds);
 
-        let input_sequence = get_input_sequence(robot_position, robot_facing_direction, &scaffolds);
+        let machine_specific_sequence = prepare_input_sequence_for_specific_machine(&input_sequence)
+            .ok_or_else(|| DayError::unsolvable("the path can't be split into three movement functions"))?;
 
-        let machine_specific_sequence = prepare_input_sequence_for_specific_machine(&input_sequence);
-
-        let mut program = int_code::create_program(input);
-        program.day_17_part_two_initialize();
+        let mut program = camera.program.clone();
+        program.day_17_part_two_initialize()?;
 
         for i in machine_specific_sequence {
             program.push_input(i as i128);
@@ -36,30 +59,25 @@ impl day_tasks::DayTasks for Day17 {
 
         program.run_until_stopped();
 
-        program.get_last_output().map(|i| i.to_string()).unwrap_or("- Something went wrong -".to_string())
+        program
+            .get_last_output()
+            .map(Answer::from)
+            .ok_or_else(|| program.unexpected_stop("the vacuum robot didn't report the collected dust"))
     }
 }
 
-fn prepare_input_sequence_for_specific_machine (original_sequence: &Vec<i32>) -> Vec<i32> {
+fn prepare_input_sequence_for_specific_machine (original_sequence: &[i32]) -> Option<Vec<i32>> {
     
-    fn get_main_a_b_and_c (a_count: i32, b_count: i32, c_count: i32, original_sequence: &Vec<i32>) ->  Option<(Vec<i32>, Vec<i32>, Vec<i32>, Vec<i32>)>{
-        fn compare (x: &Vec<i32>, y: &Vec<i32>) -> bool {
-            if x.len() != y.len() { false }
-            else { x.iter().zip(y.iter()).all(|(i_x, i_y)| *i_x == *i_y) }
-        }
-
-        fn compare_sequence_start_with_segment (sequence: &Vec<i32>, segment: &Option<Vec<i32>>, ascii: i32) -> Option<(i32, Vec<i32>)> {
-            if let Some(segment) = segment {
-                let sequence_start = sequence.iter().take(segment.len()).map(|i| *i).collect::<Vec<i32>>();
-                if compare(&sequence_start, segment) { Some((ascii, sequence.iter().skip(segment.len() + 1).map(|i| *i).collect::<Vec<i32>>())) } else { None }
-            }
-            else { None }
+    fn get_main_a_b_and_c (a_count: i32, b_count: i32, c_count: i32, original_sequence: &[i32]) ->  Option<(MovementFunction, MovementFunction, MovementFunction, MovementFunction)>{
+        fn compare_sequence_start_with_segment (sequence: &[i32], segment: &Option<Vec<i32>>, ascii: i32) -> Option<(i32, Vec<i32>)> {
+            let segment = segment.as_ref()?;
+            if sequence.starts_with(segment) { Some((ascii, sequence.iter().skip(segment.len() + 1).copied().collect::<Vec<i32>>())) } else { None }
         }
 
-        fn get_fitting_segment (sequence: &Vec<i32>, a: &Option<Vec<i32>>, b: &Option<Vec<i32>>, c: &Option<Vec<i32>>) -> Option<(i32, Vec<i32>)> {
-            compare_sequence_start_with_segment(sequence, a, 'A' as u8 as i32)
-                .or_else(|| compare_sequence_start_with_segment(sequence, b, 'B' as u8 as i32))
-                .or_else(|| compare_sequence_start_with_segment(sequence, c, 'C' as u8 as i32))
+        fn get_fitting_segment (sequence: &[i32], a: &Option<Vec<i32>>, b: &Option<Vec<i32>>, c: &Option<Vec<i32>>) -> Option<(i32, Vec<i32>)> {
+            compare_sequence_start_with_segment(sequence, a, b'A' as i32)
+                .or_else(|| compare_sequence_start_with_segment(sequence, b, b'B' as i32))
+                .or_else(|| compare_sequence_start_with_segment(sequence, c, b'C' as i32))
         }
 
         let mut main: Vec<i32> = Vec::new();
@@ -68,7 +86,7 @@ fn prepare_input_sequence_for_specific_machine (original_sequence: &Vec<i32>) ->
         let mut c: Option<Vec<i32>> = None;
         let mut first_main_insertion = true;
 
-        let mut to_be_processed: Vec<i32> = original_sequence.iter().map(|i| *i).collect();
+        let mut to_be_processed: Vec<i32> = original_sequence.to_vec();
 
         while !to_be_processed.is_empty() {
             if let Some((ascii, new_sequence)) = get_fitting_segment(&to_be_processed, &a, &b, &c) {
@@ -80,20 +98,20 @@ fn prepare_input_sequence_for_specific_machine (original_sequence: &Vec<i32>) ->
             else {
                 if a.is_some() && b.is_some() && c.is_some() { return None }
                 let count = if a.is_none() { a_count } else if b.is_none() { b_count } else { c_count };
-                let segment = to_be_processed.iter().take(count as usize).map(|i| *i).collect::<Vec<i32>>();
+                let segment = to_be_processed.iter().take(count as usize).copied().collect::<Vec<i32>>();
+                // a function holds whole moves, it ends right before a comma or at the end of the path
+                if segment.last() == Some(&44) || to_be_processed.get(segment.len()).is_some_and(|next| *next != 44) { return None }
                 if a.is_none() { a = Some(segment) } else if b.is_none() { b = Some(segment) } else { c = Some(segment) };
             }
         }
 
-        if a.is_some() && b.is_some() && c.is_some() {
-            Some((main, a.unwrap(), b.unwrap(), c.unwrap()))
-        }
-        else { None }
+        if main.len() > 20 { return None }
+        Some((main, a?, b?, c?))
     }
     
-    for a_count in (1..=20).into_iter().rev() {
-        for b_count in (1..=20).into_iter().rev() {
-            for c_count in (1..=20).into_iter().rev() {
+    for a_count in (1..=20).rev() {
+        for b_count in (1..=20).rev() {
+            for c_count in (1..=20).rev() {
                 if let Some((main, a, b, c)) = get_main_a_b_and_c(a_count, b_count, c_count, original_sequence) {
                     let mut output: Vec<i32> = Vec::new();
                     output.extend(main);
@@ -104,15 +122,15 @@ fn prepare_input_sequence_for_specific_machine (original_sequence: &Vec<i32>) ->
                     output.push(10);
                     output.extend(c);
                     output.push(10);
-                    output.push('n' as u8 as i32);
+                    output.push(b'n' as i32);
                     output.push(10);
-                    return output;
+                    return Some(output);
                 }
             }
         }
     }
 
-    Vec::new()
+    None
 }
 
 fn get_input_sequence (start_position: (i128, i128), start_direction: i32, map: &HashSet<(i128,i128)>) -> Vec<i32> {
@@ -139,7 +157,7 @@ fn get_input_sequence (start_position: (i128, i128), start_direction: i32, map:
     let mut origin_direction = start_direction;
     let mut first_iteration = true;
 
-    while let Some((ascii, direction)) = next_direction(origin, origin_direction, &map) {
+    while let Some((ascii, direction)) = next_direction(origin, origin_direction, map) {
         if !first_iteration { output.push(44) };
         output.push(ascii);
         output.push(44);
@@ -149,11 +167,11 @@ fn get_input_sequence (start_position: (i128, i128), start_direction: i32, map:
             3 => (-1, 0),
             _ => (1, 0)
         };
-        let mut step_count = if first_iteration { -1 } else { 0 };
+        let mut step_count = 0;
         origin_direction = direction;
         origin = (origin.0 + step.0, origin.1 + step.1);
         while map.contains(&origin) {
-            step_count = step_count + 1;
+            step_count += 1;
             origin = (origin.0 + step.0, origin.1 + step.1);
         } 
         origin = (origin.0 - step.0, origin.1 - step.1);
@@ -166,8 +184,8 @@ fn get_input_sequence (start_position: (i128, i128), start_direction: i32, map:
     output
 }
 
-fn get_map_and_robot_status (text_code: &String, ) -> (HashSet<(i128,i128)>, (i128, i128), i32) {
-    let mut program = int_code::create_program(text_code);
+fn get_map_and_robot_status (program: &int_code::IntCodeProgram) -> Result<(Scaffolds, (i128, i128), i32), int_code::VmFault> {
+    let mut program = program.clone();
         let mut scaffolds: HashSet<(i128,i128)> = HashSet::new();
 
         let mut ascii_text: Vec<i128> = Vec::new();
@@ -175,6 +193,7 @@ fn get_map_and_robot_status (text_code: &String, ) -> (HashSet<(i128,i128)>, (i1
         while let Some(next) = program.run_until_next_output() {
             ascii_text.push(next);
         }
+        program.check()?;
 
         let mut x = 0;
         let mut y = 0;
@@ -184,15 +203,14 @@ fn get_map_and_robot_status (text_code: &String, ) -> (HashSet<(i128,i128)>, (i1
 
         for tile in ascii_text {
             if tile == 46 {
-                x = x + 1;
+                x += 1;
             }
             else if tile == 10 {
                 x = 0;
-                y = y + 1;
+                y += 1;
             }
             else {
                 scaffolds.insert((x, y));
-                x = x + 1;
                 if tile != 35 {
                     robot_x = x;
                     robot_y = y;
@@ -204,8 +222,87 @@ fn get_map_and_robot_status (text_code: &String, ) -> (HashSet<(i128,i128)>, (i1
                         _ => 1
                     }
                 }
+                x += 1;
             }
         }
 
-        (scaffolds, (robot_x, robot_y), robot_facing_direction)
+        Ok((scaffolds, (robot_x, robot_y), robot_facing_direction))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answer::Answer;
+    use crate::assembler;
+    use crate::day_tasks::{DayTasks, Part};
+
+    const ALIGNMENT_VIEW: &str = "\
+..#..........
+..#..........
+#######...###
+#.#...#...#.#
+#############
+..#...#...#..
+..^########..
+";
+
+    const PATH_VIEW: &str = "\
+#######...#####
+#.....#...#...#
+#.....#...#...#
+......#...#...#
+......#...###.#
+......#.....#.#
+^########...#.#
+......#.#...#.#
+......#########
+........#...#..
+....#########..
+....#...#......
+....#...#......
+....#...#......
+....#####......
+";
+
+    /// A camera that prints `view`.
+    fn camera (view: &str) -> Camera {
+        let source = format!("
+        ARB #view
+loop:   JZ [rb], end
+        OUT [rb]
+        ARB #1
+        JZ #0, loop
+end:    HLT
+view:   .string \"{}\"
+        .data 0
+", view.replace('\n', "\\n"));
+        Day17.parse(&assembler::assemble(&source).unwrap()).unwrap()
+    }
+
+    fn ascii (codes: &[i32]) -> String {
+        codes.iter().map(|code| *code as u8 as char).collect()
+    }
+
+    #[test]
+    fn sums_the_alignment_parameters () {
+        assert_eq!(Day17.task(Part::First, &camera(ALIGNMENT_VIEW)).unwrap(), Answer::from(76));
+    }
+
+    #[test]
+    fn splits_the_path_into_movement_functions () {
+        let camera = camera(PATH_VIEW);
+        let path = get_input_sequence(camera.robot_position, camera.robot_facing_direction, &camera.scaffolds);
+        assert_eq!(ascii(&path), "R,8,R,8,R,4,R,4,R,8,L,6,L,2,R,4,R,4,R,8,R,8,R,8,L,6,L,2");
+        let routines = ascii(&prepare_input_sequence_for_specific_machine(&path).unwrap());
+        let routines = routines.lines().collect::<Vec<&str>>();
+        assert_eq!(routines.len(), 5);
+        assert_eq!(routines[4], "n");
+        assert!(routines.iter().all(|routine| routine.len() <= 20));
+        let expanded = routines[0]
+            .split(',')
+            .map(|function| routines[(function.as_bytes()[0] - b'A') as usize + 1])
+            .collect::<Vec<&str>>()
+            .join(",");
+        assert_eq!(expanded, ascii(&path));
+    }
 }
@@ -0,0 +1,473 @@
+use crate::answer::Answer;
+use crate::day_error::DayError;
+use crate::day_tasks;
+use crate::{debug, trace};
+use crate::input;
+use std::collections::{HashMap, HashSet, BinaryHeap};
+use std::cmp::Ordering;
+
+pub struct Day18;
+
+type Vault = HashMap<(i32, i32), Node>;
+
+pub struct Vaults {
+    /// The map as given.
+    single: Vault,
+    /// The map with the entrance split into four, one robot per quadrant.
+    split: Vault
+}
+
+pub struct Node {
+    up: Option<((i32, i32), i32)>,
+    down: Option<((i32, i32), i32)>,
+    left: Option<((i32, i32), i32)>,
+    right: Option<((i32, i32), i32)>,
+    node_type: NodeType
+}
+
+#[derive(PartialEq, Eq)]
+struct PathState {
+    position: (i32, i32),
+    distance: i32,
+    /// The doors passed on the way, one bit per letter.
+    doors: u32
+}
+
+impl Ord for PathState {
+    fn cmp(&self, other: &PathState) -> Ordering {
+        other.distance.cmp(&self.distance)
+    }
+}
+
+impl PartialOrd for PathState {
+    fn partial_cmp(&self, other: &PathState) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(PartialEq, Eq)]
+struct SearchState {
+    distance: i32,
+    robots: Vec<(i32, i32)>,
+    keys: u32
+}
+
+impl Ord for SearchState {
+    fn cmp(&self, other: &SearchState) -> Ordering {
+        other.distance.cmp(&self.distance)
+    }
+}
+
+impl PartialOrd for SearchState {
+    fn partial_cmp(&self, other: &SearchState) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The shortest way from an entrance or key to another key.
+struct Route {
+    target: (i32, i32),
+    key: u32,
+    distance: i32,
+    doors: u32
+}
+
+#[derive(Hash, Eq, PartialEq, Clone, Copy, Debug)]
+enum NodeType {
+    Normal,
+    StartingPoint,
+    Key(char),
+    Door(char)
+}
+
+impl day_tasks::DayTasks for Day18 {
+    type Input = Vaults;
+
+    fn year (&self) -> String {
+        "2019".to_string()
+    }
+    fn day_number (&self) -> String {
+        "18".to_string()
+    }
+    fn parse (&self, input: &str) -> Result<Vaults, DayError> {
+        Ok(Vaults { single: parse(input), split: parse(&split_entrance(input)) })
+    }
+    fn task_0 (&self, vaults: &Vaults) -> Result<Answer, DayError> {
+        Ok(Answer::from(collect_all_keys(&vaults.single)?))
+    }
+    fn task_1 (&self, vaults: &Vaults) -> Result<Answer, DayError> {
+        Ok(Answer::from(collect_all_keys(&vaults.split)?))
+    }
+}
+
+/// Dijkstra over the robot positions and the collected keys. Robots only ever stop at keys,
+/// so the routes between entrances and keys are computed once up front.
+fn collect_all_keys (whole_map: &Vault) -> Result<i32, DayError> {
+    let mut entrances = whole_map
+        .iter()
+        .filter(|(_, node)| node.node_type == NodeType::StartingPoint)
+        .map(|(position, _)| *position)
+        .collect::<Vec<(i32, i32)>>();
+    if entrances.is_empty() {
+        return Err(DayError::parse(1, "the map has no starting point '@'"));
+    }
+    entrances.sort();
+    let keys = get_keys(whole_map);
+    let all_keys = keys.keys().fold(0, |mask, key| mask | key_bit(*key));
+    let routes = entrances
+        .iter()
+        .chain(keys.values())
+        .map(|position| (*position, get_routes(*position, whole_map)))
+        .collect::<HashMap<(i32, i32), Vec<Route>>>();
+
+    let mut heap: BinaryHeap<SearchState> = BinaryHeap::new();
+    heap.push(SearchState { distance: 0, robots: entrances, keys: 0 });
+    let mut visited: HashSet<(Vec<(i32, i32)>, u32)> = HashSet::new();
+
+    while let Some(state) = heap.pop() {
+        if state.keys == all_keys {
+            debug!("collected {} keys with {} robots in {} steps, {} states explored", keys.len(), state.robots.len(), state.distance, visited.len());
+            return Ok(state.distance);
+        }
+        if !visited.insert((state.robots.clone(), state.keys)) {
+            continue;
+        }
+        if visited.len().is_multiple_of(10000) {
+            trace!("{} states explored, at distance {} with {} of {} keys", visited.len(), state.distance, state.keys.count_ones(), keys.len());
+        }
+        for (index, robot) in state.robots.iter().enumerate() {
+            for route in &routes[robot] {
+                if state.keys & route.key != 0 || route.doors & !state.keys != 0 {
+                    continue;
+                }
+                let mut robots = state.robots.clone();
+                robots[index] = route.target;
+                heap.push(SearchState { distance: state.distance + route.distance, robots, keys: state.keys | route.key });
+            }
+        }
+    }
+
+    Err(DayError::unsolvable("not all keys can be reached"))
+}
+
+fn key_bit (key: char) -> u32 {
+    1 << (key as u32 - 'a' as u32)
+}
+
+fn get_keys (whole_map: &Vault) -> HashMap<char, (i32, i32)> {
+    whole_map
+        .iter()
+        .filter_map(|(position, node)| match node.node_type { NodeType::Key(c) => Some((c, *position)), _ => None })
+        .collect::<HashMap<char, (i32, i32)>>()
+}
+
+fn get_routes (start: (i32, i32), whole_map: &Vault) -> Vec<Route> {
+    let mut heap: BinaryHeap<PathState> = BinaryHeap::new();
+    heap.push(PathState { position: start, distance: 0, doors: 0 });
+    let mut visited: HashSet<(i32, i32)> = HashSet::new();
+    let mut routes = Vec::new();
+
+    while let Some(current_state) = heap.pop() {
+        if !visited.insert(current_state.position) {
+            continue;
+        }
+        let current_node = match whole_map.get(&current_state.position) {
+            Some(node) => node,
+            None => continue
+        };
+        let doors = match current_node.node_type {
+            NodeType::Door(c) => current_state.doors | key_bit(c),
+            _ => current_state.doors
+        };
+        if let NodeType::Key(c) = current_node.node_type {
+            if current_state.position != start {
+                routes.push(Route { target: current_state.position, key: key_bit(c), distance: current_state.distance, doors });
+            }
+        }
+        for (node_position, distance) in [current_node.up, current_node.down, current_node.left, current_node.right]
+            .iter()
+            .filter_map(|opt| *opt)
+            .filter(|(pos, _)| !visited.contains(pos)){
+            heap.push(PathState { position: node_position, distance: current_state.distance + distance, doors });
+        };
+    }
+
+    routes
+}
+
+/// Replaces the 3x3 area around a single entrance with four entrances separated by walls.
+/// Maps which already have several entrances are kept as they are.
+fn split_entrance (input: &str) -> String {
+    let mut grid = input::grid(input);
+    let entrances = grid
+        .iter()
+        .enumerate()
+        .flat_map(|(y, row)| row.iter().enumerate().filter(|(_, c)| **c == '@').map(move |(x, _)| (x, y)))
+        .collect::<Vec<(usize, usize)>>();
+    if let [(x, y)] = entrances[..] {
+        if x > 0 && y > 0 {
+            for (dy, row) in ["@#@", "###", "@#@"].iter().enumerate() {
+                for (dx, c) in row.chars().enumerate() {
+                    if let Some(cell) = grid.get_mut(y + dy - 1).and_then(|line| line.get_mut(x + dx - 1)) {
+                        *cell = c;
+                    }
+                }
+            }
+        }
+    }
+    grid
+        .into_iter()
+        .map(|row| row.into_iter().collect::<String>())
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn parse (input: &str) -> Vault {
+    fn removing_criteria (node: &Node, endpoint_count: usize) -> bool {
+        if node.node_type != NodeType::Normal { false }
+        else {
+            [node.up, node.down, node.left, node.right]
+                .iter()
+                .filter_map(|x| *x)
+                .count() == endpoint_count }
+    }
+    let mut map = input::grid(input)
+        .into_iter()
+        .enumerate()
+        .flat_map(|(y, row)| 
+            row
+                .into_iter()
+                .enumerate()
+                .filter(|(_, c)| *c != '#')
+                .map(move |(x, c)| (x, y, c))
+        )
+        .map(|(x, y, c)| {
+            let node = Node {
+                up: None,
+                down: None,
+                left: None,
+                right: None,
+                node_type: match c {
+                    'a'..='z' => NodeType::Key(c),
+                    'A'..='Z' => NodeType::Door(c.to_ascii_lowercase()),
+                    '@' => NodeType::StartingPoint,
+                    _ => NodeType::Normal
+                }
+            };
+            ((x as i32, y as i32), node)
+        })
+        .collect::<HashMap<(i32, i32), Node>>();
+
+    map = map
+        .iter()
+        .map(|((x, y), node)| {
+            ((*x, *y), Node {
+                up: if map.contains_key(&(*x, *y - 1)) { Some(((*x, *y - 1), 1))} else { None },
+                down: if map.contains_key(&(*x, *y + 1)) { Some(((*x, *y + 1), 1))} else { None },
+                left: if map.contains_key(&(*x - 1, *y)) { Some(((*x - 1, *y), 1))} else { None },
+                right: if map.contains_key(&(*x + 1, *y)) { Some(((*x + 1, *y), 1))} else { None },
+                ..*node
+            })
+        })
+        .collect::<HashMap<(i32, i32), Node>>();
+    
+    // remove with only two endpoints
+    let binary_nodes = 
+        map
+            .iter()
+            .filter(|(_, node)| removing_criteria(node, 2usize))
+            .map(|(pos, _)| *pos)
+            .collect::<Vec<(i32, i32)>>();
+    
+    for node_position in binary_nodes {
+        let node = map.get(&node_position);
+        if let Some(node) = node {
+            let option = match (node.up, node.down, node.left, node.right) {
+                (Some(t_0), Some(t_1), None, None)
+                | (Some(t_0), None, Some(t_1), None)
+                | (Some(t_0), None, None, Some(t_1))
+                | (None, Some(t_0), Some(t_1), None)
+                | (None, Some(t_0), None, Some(t_1))
+                | (None, None, Some(t_0), Some(t_1)) => Some((t_0, t_1)),
+                _ => None
+            };
+            if let Some(((node_0_position, dist_0), (node_1_position, dist_1))) = option {
+                fn replace_proxy (
+                    node: &Node,
+                    proxy_position: (i32, i32),
+                    other_position: (i32, i32),
+                    new_distance: i32) -> Node {
+                    fn replace_if_equal_to_proxy (
+                        before: Option<((i32, i32), i32)>, 
+                        proxy_position: (i32, i32),
+                        new_position: (i32, i32),
+                        new_distance: i32) -> Option<((i32, i32), i32)> {
+                        if let Some((before_position, _)) = before {
+                            if before_position == proxy_position { Some((new_position, new_distance))}
+                            else { before }
+                        } 
+                        else { None }
+                    }
+                    Node {
+                        up: replace_if_equal_to_proxy(node.up, proxy_position, other_position, new_distance),
+                        down: replace_if_equal_to_proxy(node.down, proxy_position, other_position, new_distance),
+                        left: replace_if_equal_to_proxy(node.left, proxy_position, other_position, new_distance),
+                        right: replace_if_equal_to_proxy(node.right, proxy_position, other_position, new_distance),
+                        ..*node
+                    }
+                }
+                let dist = dist_0 + dist_1;
+                if let Some(node_0) = map.get(&node_0_position) {
+                    let new_node_0 = replace_proxy(node_0, node_position, node_1_position, dist);
+                    map.insert(node_0_position, new_node_0);
+                }
+                if let Some(node_1) = map.get(&node_1_position) {
+                    let new_node_1 = replace_proxy(node_1, node_position, node_0_position, dist);
+                    map.insert(node_1_position, new_node_1);
+                }
+            }
+        }
+        map.remove(&node_position);
+    }
+
+    // remove dead ends
+    let dead_end_nodes = 
+    map
+        .iter()
+        .filter(|(_, node)| removing_criteria(node, 1usize))
+        .map(|(pos, _)| *pos)
+        .collect::<Vec<(i32, i32)>>();
+
+    for node_position in dead_end_nodes {
+        let node = map.get(&node_position);
+        if let Some(node) = node {
+            let option = match (node.up, node.down, node.left, node.right) {
+                (Some(t), None, None, None)
+                | (None, Some(t), None, None)
+                | (None, None, Some(t), None)
+                | (None, None, None, Some(t)) => Some(t),
+                _ => None
+            };
+            if let Some((node_0_position, _)) = option {
+                fn replace_proxy (
+                    node: &Node,
+                    proxy_position: (i32, i32)) -> Node {
+                    fn replace_if_equal_to_proxy (
+                        before: Option<((i32, i32), i32)>, 
+                        proxy_position: (i32, i32)) -> Option<((i32, i32), i32)> {
+                        if let Some((before_position, _)) = before {
+                            if before_position == proxy_position { None }
+                            else { before }
+                        } 
+                        else { None }
+                    }
+                    Node {
+                        up: replace_if_equal_to_proxy(node.up, proxy_position),
+                        down: replace_if_equal_to_proxy(node.down, proxy_position),
+                        left: replace_if_equal_to_proxy(node.left, proxy_position),
+                        right: replace_if_equal_to_proxy(node.right, proxy_position),
+                        ..*node
+                    }
+                }
+                if let Some(node_0) = map.get(&node_0_position) {
+                    let new_node_0 = replace_proxy(node_0, node_position);
+                    map.insert(node_0_position, new_node_0);
+                }
+            }
+        }
+        map.remove(&node_position);
+    }
+
+    map
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_tasks::Part;
+    use crate::examples::{self, Example};
+
+    const EXAMPLES: &[Example] = &[
+        (concat!(
+            "#########\n",
+            "#b.A.@.a#\n",
+            "#########"),
+            Part::First, "8"),
+        (concat!(
+            "########################\n",
+            "#f.D.E.e.C.b.A.@.a.B.c.#\n",
+            "######################.#\n",
+            "#d.....................#\n",
+            "########################"),
+            Part::First, "86"),
+        (concat!(
+            "########################\n",
+            "#...............b.C.D.f#\n",
+            "#.######################\n",
+            "#.....@.a.B.c.d.A.e.F.g#\n",
+            "########################"),
+            Part::First, "132"),
+        (concat!(
+            "#################\n",
+            "#i.G..c...e..H.p#\n",
+            "########.########\n",
+            "#j.A..b...f..D.o#\n",
+            "########@########\n",
+            "#k.E..a...g..B.n#\n",
+            "########.########\n",
+            "#l.F..d...h..C.m#\n",
+            "#################"),
+            Part::First, "136"),
+        (concat!(
+            "########################\n",
+            "#@..............ac.GI.b#\n",
+            "###d#e#f################\n",
+            "###A#B#C################\n",
+            "###g#h#i################\n",
+            "########################"),
+            Part::First, "81"),
+        (concat!(
+            "#######\n",
+            "#a.#Cd#\n",
+            "##...##\n",
+            "##.@.##\n",
+            "##...##\n",
+            "#cB#Ab#\n",
+            "#######"),
+            Part::Second, "8"),
+        (concat!(
+            "###############\n",
+            "#d.ABC.#.....a#\n",
+            "######@#@######\n",
+            "###############\n",
+            "######@#@######\n",
+            "#b.....#.....c#\n",
+            "###############"),
+            Part::Second, "24"),
+        (concat!(
+            "#############\n",
+            "#DcBa.#.GhKl#\n",
+            "#.###@#@#I###\n",
+            "#e#d#####j#k#\n",
+            "###C#@#@###J#\n",
+            "#fEbA.#.FgHi#\n",
+            "#############"),
+            Part::Second, "32"),
+        (concat!(
+            "#############\n",
+            "#g#f.D#..h#l#\n",
+            "#F###e#E###.#\n",
+            "#dCba@#@BcIJ#\n",
+            "#############\n",
+            "#nK.L@#@G...#\n",
+            "#M###N#H###.#\n",
+            "#o#m..#i#jk.#\n",
+            "#############"),
+            Part::Second, "72")
+    ];
+
+    #[test]
+    fn worked_examples () {
+        examples::check(&Day18, EXAMPLES);
+    }
+}
@@ -0,0 +1,108 @@
+use crate::answer::Answer;
+use crate::day_error::DayError;
+use crate::day_tasks;
+use super::int_code;
+
+pub struct Day19;
+
+impl day_tasks::DayTasks for Day19 {
+    type Input = int_code::IntCodeProgram;
+
+    fn year (&self) -> String {
+        "2019".to_string()
+    }
+    fn day_number (&self) -> String {
+        "19".to_string()
+    }
+    fn parse (&self, input: &str) -> Result<int_code::IntCodeProgram, DayError> {
+        int_code::create_program(input)
+    }
+    fn task_0 (&self, program: &int_code::IntCodeProgram) -> Result<Answer, DayError> {
+        let mut i = 0;
+
+        for y in 0..50 {
+            for x in 0..50 {
+                if check_position (x, y, program)? {
+                    i += 1;
+                }
+            }
+        }
+
+        Ok(Answer::from(i))
+    }
+    fn task_1 (&self, program: &int_code::IntCodeProgram) -> Result<Answer, DayError> {
+        // the left edge runs 99 rows below the right edge, the square fits once they are 99 apart
+        let mut left = (5, 8);
+        let mut right = (5, 8);
+        for _ in 0..99 {
+            left = next_left_edge(left, program)?;
+        }
+        while right.0 - left.0 != 99 {
+            left = next_left_edge(left, program)?;
+            right = next_right_edge(right, program)?;
+        }
+        Ok(Answer::from(left.0 * 10_000 + right.1))
+    }
+}
+
+/// The leftmost point of the beam in the row below `(x, y)`, the beam only moves to the right.
+fn next_left_edge ((x, y): (i128, i128), program: &int_code::IntCodeProgram) -> Result<(i128, i128), int_code::VmFault> {
+    let mut new_x = x;
+    while !check_position(new_x, y + 1, program)? {
+        new_x += 1;
+    }
+    Ok((new_x, y + 1))
+}
+
+/// The rightmost point of the beam in the row below `(x, y)`.
+fn next_right_edge ((x, y): (i128, i128), program: &int_code::IntCodeProgram) -> Result<(i128, i128), int_code::VmFault> {
+    let mut new_x = x + 1;
+    while check_position(new_x, y + 1, program)? {
+        new_x += 1;
+    }
+    Ok((new_x - 1, y + 1))
+}
+
+fn check_position (x: i128, y: i128, program: &int_code::IntCodeProgram) -> Result<bool, int_code::VmFault> {
+    let mut program = program.clone();
+    program.push_input(x);
+    program.push_input(y);
+    let output = program.run_until_next_output();
+    program.check()?;
+    Ok(output == Some(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_tasks::{DayTasks, Part};
+    use crate::assembler;
+
+    // a beam covering x <= y <= 2x, row y holds y / 2 + 1 points and the square first fits at (198, 297)
+    const BEAM: &str = "
+        IN -> [x]
+        IN -> [y]
+        MUL [x], #2 -> [t]
+        LT [t], [y] -> [below]
+        LT [y], [x] -> [above]
+        ADD [below], [above] -> [t]
+        EQ [t], #0 -> [t]
+        OUT [t]
+        HLT
+x:      .data 0
+y:      .data 0
+t:      .data 0
+below:  .data 0
+above:  .data 0
+";
+
+    #[test]
+    fn measures_the_beam () {
+        let program = Day19.parse(&assembler::assemble(BEAM).unwrap()).unwrap();
+        assert_eq!(Day19.task(Part::First, &program).unwrap(), Answer::from(650));
+        assert_eq!(Day19.task(Part::Second, &program).unwrap(), Answer::from(1_980_297));
+        // a drone that faults fails the search instead of counting as outside the beam
+        let program = Day19.parse("3,100,3,100,42").unwrap();
+        assert!(Day19.task(Part::Second, &program).is_err());
+    }
+}
@@ -1,19 +1,45 @@
+use crate::answer::Answer;
+use crate::day_error::DayError;
 use crate::day_tasks;
 use std::collections::{HashMap, HashSet, BinaryHeap};
 use std::cmp::Ordering;
+use std::convert::TryFrom;
+
+type Position = (i32, i32);
+type Map = HashMap<Position, Node>;
 
 pub struct Day20;
 
+/// Part one treats portals as plain corridors, part two as links between the recursion levels.
+pub struct Mazes {
+    flat: (Map, Position, Position),
+    recursive: Maze
+}
+
 impl day_tasks::DayTasks for Day20 {
+    type Input = Mazes;
+
+    fn year (&self) -> String {
+        "2019".to_string()
+    }
     fn day_number (&self) -> String {
         "20".to_string()
     }
-    fn task_0 (&self, input: &String) -> String {
-        let (map, start, finish) = parse(input);
-        shortest_path(start, finish, &map).expect("- no result -").to_string()
+    fn parse (&self, input: &str) -> Result<Mazes, DayError> {
+        Ok(Mazes { flat: parse(input)?, recursive: parse_1(input)? })
+    }
+    fn task_0 (&self, mazes: &Mazes) -> Result<Answer, DayError> {
+        let (map, start, finish) = &mazes.flat;
+        shortest_path(*start, *finish, map)
+            .map(Answer::from)
+            .ok_or_else(|| DayError::unsolvable("there is no path from AA to ZZ"))
     }
-    fn task_1 (&self, input: &String) -> String {
-        parse_1(input).shortest_path().expect("- no result -").to_string()
+    fn task_1 (&self, mazes: &Mazes) -> Result<Answer, DayError> {
+        mazes
+            .recursive
+            .shortest_path()
+            .map(Answer::from)
+            .ok_or_else(|| DayError::unsolvable("there is no path from AA to ZZ through the recursive maze"))
     }
 }
 
@@ -28,7 +54,7 @@ fn shortest_path (
 
     while let Some(current_state) = heap.pop() {
         visited.insert(current_state.position);
-        let current_node = if let Some(node) = map.get(&current_state.position) { node } else { panic!() };
+        let current_node = map.get(&current_state.position)?;
         if current_state.position == finish {
             return Some(current_state.distance)
         }
@@ -120,7 +146,7 @@ impl Maze {
     
         while let Some(current_state) = heap.pop() {
             visited.insert((current_state.position, current_state.floor));
-            let current_node = if let Some(node) = self.map.get(&current_state.position) { node } else { panic!() };
+            let current_node = self.map.get(&current_state.position)?;
             if current_state.position == self.finish && current_state.floor == 0 {
                 return Some(current_state.distance)
             }
@@ -131,7 +157,7 @@ impl Maze {
                 .iter()
                 .filter_map(|opt| *opt)
                 .filter(|(pos, _, floor)| !visited.contains(&(*pos, *floor))){
-                heap.push(PathState1 { position: node_position, distance: current_state.distance + distance, floor: floor });
+                heap.push(PathState1 { position: node_position, distance: current_state.distance + distance, floor });
             };
         }
     
@@ -143,10 +169,10 @@ impl Maze {
             .or_else(|| {
                 if let Some((label, layer)) = self.pos_to_label_and_layer.get(&possible_label_position) {
                     if *layer == Layer::Inner {
-                        Some((*self.outer_layer_to_port_position.get(label).expect("- unexpected -"), 1, current_floor + 1))
+                        Some((*self.outer_layer_to_port_position.get(label)?, 1, current_floor + 1))
                     }
                     else if *layer == Layer::Outer && current_floor > 0 {
-                        Some((*self.inner_layer_to_port_position.get(label).expect("- unexpected -"), 1, current_floor - 1))
+                        Some((*self.inner_layer_to_port_position.get(label)?, 1, current_floor - 1))
                     }
                     else { None }
                 }
@@ -155,7 +181,7 @@ impl Maze {
     }
 }
 
-fn parse_1 (input: &String) -> Maze {
+fn parse_1 (input: &str) -> Result<Maze, DayError> {
     fn removing_criteria (node: &Node, endpoint_count: usize) -> bool {
         [node.up, node.down, node.left, node.right]
             .iter()
@@ -168,10 +194,10 @@ fn parse_1 (input: &String) -> Maze {
     let outer_up = 1;
     let outer_down = lines.len() as i32 - 2;
     let outer_left = 1;
-    let outer_right = lines.iter().map(|line| line.len()).max().expect("no lines in input") as i32 - 2;
+    let outer_right = lines.iter().map(|line| line.len()).max().ok_or_else(|| DayError::parse(1, "the maze is empty"))? as i32 - 2;
 
-    let mut starting_position = (0, 0);
-    let mut finish_position = (0, 0);
+    let mut starting_position = None;
+    let mut finish_position = None;
 
     let mut pos_to_label_and_layer: HashMap<(i32, i32), (String, Layer)> = HashMap::new();
     let mut outer_layer_to_port_position: HashMap<String, (i32, i32)> = HashMap::new();
@@ -205,30 +231,25 @@ fn parse_1 (input: &String) -> Maze {
             line
                 .chars()
                 .enumerate()
-                .filter(|(_, c)| match *c { 'A'..='Z' => true, _ => false })
+                .filter(|(_, c)| c.is_ascii_uppercase())
                 .map(move |(x, _)| (x, y))) {
         let (x, y) = (x as i32, y as i32);
         if let Some((direction, port_position)) = 
-            if let Some(_) = map.get(&(x, y + 1)) { Some((Direction::Up, (x, y + 1))) }
-            else if let Some(_) = map.get(&(x, y - 1)) { Some((Direction::Down, (x, y - 1))) }
-            else if let Some(_) = map.get(&(x + 1, y)) { Some((Direction::Left, (x + 1, y))) }
-            else if let Some(_) = map.get(&(x - 1, y)) { Some((Direction::Right, (x - 1, y))) }
+            if map.contains_key(&(x, y + 1)) { Some((Direction::Up, (x, y + 1))) }
+            else if map.contains_key(&(x, y - 1)) { Some((Direction::Down, (x, y - 1))) }
+            else if map.contains_key(&(x + 1, y)) { Some((Direction::Left, (x + 1, y))) }
+            else if map.contains_key(&(x - 1, y)) { Some((Direction::Right, (x - 1, y))) }
             else { None } {
             let layer = 
                 if x == outer_left || x == outer_right || y == outer_up || y == outer_down { Layer::Outer } 
                 else { Layer::Inner };
-            let label = match direction {
-                Direction::Up => format!("{}{}", lines.get(y as usize - 1).unwrap().chars().nth(x as usize).unwrap(), lines.get(y as usize).unwrap().chars().nth(x as usize).unwrap()),
-                Direction::Down => format!("{}{}", lines.get(y as usize).unwrap().chars().nth(x as usize).unwrap(), lines.get(y as usize + 1).unwrap().chars().nth(x as usize).unwrap()),
-                Direction::Left => format!("{}{}", lines.get(y as usize).unwrap().chars().nth(x as usize - 1).unwrap(), lines.get(y as usize).unwrap().chars().nth(x as usize).unwrap()),
-                Direction::Right => format!("{}{}", lines.get(y as usize).unwrap().chars().nth(x as usize).unwrap(), lines.get(y as usize).unwrap().chars().nth(x as usize + 1).unwrap()),
-            };
+            let label = read_label(&lines, x, y, direction)?;
             port_positions.insert(port_position);
             if label == "AA" {
-                starting_position = port_position;
+                starting_position = Some(port_position);
             }
             else if label == "ZZ" {
-                finish_position = port_position;
+                finish_position = Some(port_position);
             }
             else {
                 pos_to_label_and_layer.insert((x, y), (label.to_string(), layer));
@@ -243,6 +264,9 @@ fn parse_1 (input: &String) -> Maze {
         }
     }
 
+    let starting_position = starting_position.ok_or_else(|| DayError::parse(1, "the maze has no entrance AA"))?;
+    let finish_position = finish_position.ok_or_else(|| DayError::parse(1, "the maze has no exit ZZ"))?;
+
     map = map 
         .iter()
         .map(|((x, y), _)| {
@@ -296,8 +320,7 @@ fn parse_1 (input: &String) -> Maze {
                         up: replace_if_equal_to_proxy(node.up, proxy_position, other_position, new_distance),
                         down: replace_if_equal_to_proxy(node.down, proxy_position, other_position, new_distance),
                         left: replace_if_equal_to_proxy(node.left, proxy_position, other_position, new_distance),
-                        right: replace_if_equal_to_proxy(node.right, proxy_position, other_position, new_distance),
-                        ..*node
+                        right: replace_if_equal_to_proxy(node.right, proxy_position, other_position, new_distance)
                     }
                 }
                 let dist = dist_0 + dist_1;
@@ -349,8 +372,7 @@ fn parse_1 (input: &String) -> Maze {
                         up: replace_if_equal_to_proxy(node.up, proxy_position),
                         down: replace_if_equal_to_proxy(node.down, proxy_position),
                         left: replace_if_equal_to_proxy(node.left, proxy_position),
-                        right: replace_if_equal_to_proxy(node.right, proxy_position),
-                        ..*node
+                        right: replace_if_equal_to_proxy(node.right, proxy_position)
                     }
                 }
                 if let Some(node_0) = map.get(&node_0_position) {
@@ -362,17 +384,35 @@ fn parse_1 (input: &String) -> Maze {
         map.remove(&node_position);
     }
 
-    Maze { 
-        map: map, 
+    Ok(Maze { 
+        map, 
         start: starting_position, 
         finish: finish_position, 
-        pos_to_label_and_layer: pos_to_label_and_layer, 
-        inner_layer_to_port_position: inner_layer_to_port_position,
-        outer_layer_to_port_position: outer_layer_to_port_position
+        pos_to_label_and_layer, 
+        inner_layer_to_port_position,
+        outer_layer_to_port_position
+    })
+}
+
+fn read_label (lines: &[&str], x: i32, y: i32, direction: Direction) -> Result<String, DayError> {
+    let (first, second) = match direction {
+        Direction::Up => ((x, y - 1), (x, y)),
+        Direction::Down => ((x, y), (x, y + 1)),
+        Direction::Left => ((x - 1, y), (x, y)),
+        Direction::Right => ((x, y), (x + 1, y))
+    };
+    let char_at = |(x, y): (i32, i32)| usize::try_from(y)
+        .ok()
+        .and_then(|y| lines.get(y))
+        .and_then(|line| usize::try_from(x).ok().and_then(|x| line.chars().nth(x)))
+        .filter(|c| c.is_ascii_uppercase());
+    match (char_at(first), char_at(second)) {
+        (Some(first), Some(second)) => Ok(format!("{}{}", first, second)),
+        _ => Err(DayError::parse(y as usize + 1, format!("the portal label at column {} is incomplete", x + 1)))
     }
 }
 
-fn parse (input: &String) -> (HashMap<(i32, i32), Node>, (i32, i32), (i32, i32)) {
+fn parse (input: &str) -> Result<(Map, Position, Position), DayError> {
     fn removing_criteria (node: &Node, endpoint_count: usize) -> bool {
         [node.up, node.down, node.left, node.right]
             .iter()
@@ -385,10 +425,10 @@ fn parse (input: &String) -> (HashMap<(i32, i32), Node>, (i32, i32), (i32, i32))
     let outer_up = 1;
     let outer_down = lines.len() as i32 - 2;
     let outer_left = 1;
-    let outer_right = lines.iter().map(|line| line.len()).max().expect("no lines in input") as i32 - 2;
+    let outer_right = lines.iter().map(|line| line.len()).max().ok_or_else(|| DayError::parse(1, "the maze is empty"))? as i32 - 2;
 
-    let mut starting_position = (0, 0);
-    let mut finish_position = (0, 0);
+    let mut starting_position = None;
+    let mut finish_position = None;
 
     let mut pos_to_label_and_layer: HashMap<(i32, i32), (String, Layer)> = HashMap::new();
     let mut outer_layer_to_port_position: HashMap<String, (i32, i32)> = HashMap::new();
@@ -421,29 +461,24 @@ fn parse (input: &String) -> (HashMap<(i32, i32), Node>, (i32, i32), (i32, i32))
             line
                 .chars()
                 .enumerate()
-                .filter(|(_, c)| match *c { 'A'..='Z' => true, _ => false })
+                .filter(|(_, c)| c.is_ascii_uppercase())
                 .map(move |(x, _)| (x, y))) {
         let (x, y) = (x as i32, y as i32);
         if let Some((direction, port_position)) = 
-            if let Some(_) = map.get(&(x, y + 1)) { Some((Direction::Up, (x, y + 1))) }
-            else if let Some(_) = map.get(&(x, y - 1)) { Some((Direction::Down, (x, y - 1))) }
-            else if let Some(_) = map.get(&(x + 1, y)) { Some((Direction::Left, (x + 1, y))) }
-            else if let Some(_) = map.get(&(x - 1, y)) { Some((Direction::Right, (x - 1, y))) }
+            if map.contains_key(&(x, y + 1)) { Some((Direction::Up, (x, y + 1))) }
+            else if map.contains_key(&(x, y - 1)) { Some((Direction::Down, (x, y - 1))) }
+            else if map.contains_key(&(x + 1, y)) { Some((Direction::Left, (x + 1, y))) }
+            else if map.contains_key(&(x - 1, y)) { Some((Direction::Right, (x - 1, y))) }
             else { None } {
             let layer = 
                 if x == outer_left || x == outer_right || y == outer_up || y == outer_down { Layer::Outer } 
                 else { Layer::Inner };
-            let label = match direction {
-                Direction::Up => format!("{}{}", lines.get(y as usize - 1).unwrap().chars().nth(x as usize).unwrap(), lines.get(y as usize).unwrap().chars().nth(x as usize).unwrap()),
-                Direction::Down => format!("{}{}", lines.get(y as usize).unwrap().chars().nth(x as usize).unwrap(), lines.get(y as usize + 1).unwrap().chars().nth(x as usize).unwrap()),
-                Direction::Left => format!("{}{}", lines.get(y as usize).unwrap().chars().nth(x as usize - 1).unwrap(), lines.get(y as usize).unwrap().chars().nth(x as usize).unwrap()),
-                Direction::Right => format!("{}{}", lines.get(y as usize).unwrap().chars().nth(x as usize).unwrap(), lines.get(y as usize).unwrap().chars().nth(x as usize + 1).unwrap()),
-            };
+            let label = read_label(&lines, x, y, direction)?;
             if label == "AA" {
-                starting_position = port_position;
+                starting_position = Some(port_position);
             }
             else if label == "ZZ" {
-                finish_position = port_position;
+                finish_position = Some(port_position);
             }
             else {
                 pos_to_label_and_layer.insert((x, y), (label.to_string(), layer));
@@ -458,6 +493,9 @@ fn parse (input: &String) -> (HashMap<(i32, i32), Node>, (i32, i32), (i32, i32))
         }
     }
 
+    let starting_position = starting_position.ok_or_else(|| DayError::parse(1, "the maze has no entrance AA"))?;
+    let finish_position = finish_position.ok_or_else(|| DayError::parse(1, "the maze has no exit ZZ"))?;
+
     map = map 
         .iter()
         .map(|((x, y), _)| {
@@ -536,8 +574,7 @@ fn parse (input: &String) -> (HashMap<(i32, i32), Node>, (i32, i32), (i32, i32))
                         up: replace_if_equal_to_proxy(node.up, proxy_position, other_position, new_distance),
                         down: replace_if_equal_to_proxy(node.down, proxy_position, other_position, new_distance),
                         left: replace_if_equal_to_proxy(node.left, proxy_position, other_position, new_distance),
-                        right: replace_if_equal_to_proxy(node.right, proxy_position, other_position, new_distance),
-                        ..*node
+                        right: replace_if_equal_to_proxy(node.right, proxy_position, other_position, new_distance)
                     }
                 }
                 let dist = dist_0 + dist_1;
@@ -589,8 +626,7 @@ fn parse (input: &String) -> (HashMap<(i32, i32), Node>, (i32, i32), (i32, i32))
                         up: replace_if_equal_to_proxy(node.up, proxy_position),
                         down: replace_if_equal_to_proxy(node.down, proxy_position),
                         left: replace_if_equal_to_proxy(node.left, proxy_position),
-                        right: replace_if_equal_to_proxy(node.right, proxy_position),
-                        ..*node
+                        right: replace_if_equal_to_proxy(node.right, proxy_position)
                     }
                 }
                 if let Some(node_0) = map.get(&node_0_position) {
@@ -602,5 +638,62 @@ fn parse (input: &String) -> (HashMap<(i32, i32), Node>, (i32, i32), (i32, i32))
         map.remove(&node_position);
     }
 
-    (map, starting_position, finish_position)
+    Ok((map, starting_position, finish_position))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_tasks::Part;
+    use crate::examples::{self, Example};
+
+    const EXAMPLES: &[Example] = &[
+        (concat!(
+            "         A           \n",
+            "         A           \n",
+            "  #######.#########  \n",
+            "  #######.........#  \n",
+            "  #######.#######.#  \n",
+            "  #######.#######.#  \n",
+            "  #######.#######.#  \n",
+            "  #####  B    ###.#  \n",
+            "BC...##  C    ###.#  \n",
+            "  ##.##       ###.#  \n",
+            "  ##...DE  F  ###.#  \n",
+            "  #####    G  ###.#  \n",
+            "  #########.#####.#  \n",
+            "DE..#######...###.#  \n",
+            "  #.#########.###.#  \n",
+            "FG..#########.....#  \n",
+            "  ###########.#####  \n",
+            "             Z       \n",
+            "             Z       "),
+            Part::First, "23"),
+        (concat!(
+            "         A           \n",
+            "         A           \n",
+            "  #######.#########  \n",
+            "  #######.........#  \n",
+            "  #######.#######.#  \n",
+            "  #######.#######.#  \n",
+            "  #######.#######.#  \n",
+            "  #####  B    ###.#  \n",
+            "BC...##  C    ###.#  \n",
+            "  ##.##       ###.#  \n",
+            "  ##...DE  F  ###.#  \n",
+            "  #####    G  ###.#  \n",
+            "  #########.#####.#  \n",
+            "DE..#######...###.#  \n",
+            "  #.#########.###.#  \n",
+            "FG..#########.....#  \n",
+            "  ###########.#####  \n",
+            "             Z       \n",
+            "             Z       "),
+            Part::Second, "26")
+    ];
+
+    #[test]
+    fn worked_examples () {
+        examples::check(&Day20, EXAMPLES);
+    }
 }
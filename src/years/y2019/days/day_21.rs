@@ -0,0 +1,78 @@
+use crate::answer::Answer;
+use crate::day_error::DayError;
+use crate::day_tasks;
+use super::ascii_code;
+
+pub struct Day21;
+
+impl day_tasks::DayTasks for Day21 {
+    type Input = ascii_code::AsciiCodeProgram;
+
+    fn year (&self) -> String {
+        "2019".to_string()
+    }
+    fn day_number (&self) -> String {
+        "21".to_string()
+    }
+    fn parse (&self, input: &str) -> Result<ascii_code::AsciiCodeProgram, DayError> {
+        ascii_code::create_program(input)
+    }
+    fn task_0 (&self, program: &ascii_code::AsciiCodeProgram) -> Result<Answer, DayError> {
+        let mut program = program.clone();
+        let (text, damage) = program.run_script("NOT C J\nNOT A T\nOR T J\nAND D J\nWALK\n");
+        program.check()?;
+        report_hull_damage(text, damage)
+    }
+    fn task_1 (&self, program: &ascii_code::AsciiCodeProgram) -> Result<Answer, DayError> {
+        let mut program = program.clone();
+        let (text, damage) = program.run_script("NOT C J\nNOT A T\nOR T J\nNOT B T\nOR T J\nOR E T\nOR H T\nAND D T\nAND T J\nRUN\n");
+        program.check()?;
+        report_hull_damage(text, damage)
+    }
+}
+
+fn report_hull_damage (text: String, damage: Option<i128>) -> Result<Answer, DayError> {
+    damage
+        .map(Answer::from)
+        .ok_or_else(|| DayError::unsolvable(format!("the springdroid fell into space\n{}", text.trim_end())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assembler;
+    use crate::day_tasks::{DayTasks, Part};
+
+    // reports 1000 plus the number of characters of the script up to the K of WALK or the U of RUN
+    const SPRINGDROID: &str = r#"
+.const K 75
+.const U 85
+        ARB #prompt
+print:  JZ [rb], read
+        OUT [rb]
+        ARB #1
+        JZ #0, print
+read:   IN -> [char]
+        ADD [count], #1 -> [count]
+        EQ [char], #K -> [done]
+        JNZ [done], report
+        EQ [char], #U -> [done]
+        JZ [done], read
+report: ADD [count], #1000 -> [count]
+        OUT [count]
+        HLT
+char:   .data 0
+count:  .data 0
+done:   .data 0
+prompt: .string "Input instructions:\n"
+        .data 0
+"#;
+
+    #[test]
+    fn runs_the_springscripts () {
+        let program = Day21.parse(&assembler::assemble(SPRINGDROID).unwrap()).unwrap();
+        assert_eq!(Day21.task(Part::First, &program).unwrap(), Answer::from(1035));
+        assert_eq!(Day21.task(Part::Second, &program).unwrap(), Answer::from(1070));
+        assert!(report_hull_damage("Didn't make it across:\n".to_string(), None).is_err());
+    }
+}
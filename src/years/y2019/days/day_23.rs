@@ -0,0 +1,165 @@
+use crate::answer::Answer;
+use crate::day_error::DayError;
+use crate::day_tasks;
+use crate::{debug, trace};
+use super::int_code;
+use std::collections::HashMap;
+
+pub struct Day23;
+
+impl day_tasks::DayTasks for Day23 {
+    type Input = int_code::IntCodeProgram;
+
+    fn year (&self) -> String {
+        "2019".to_string()
+    }
+    fn day_number (&self) -> String {
+        "23".to_string()
+    }
+    fn parse (&self, input: &str) -> Result<int_code::IntCodeProgram, DayError> {
+        int_code::create_program(input)
+    }
+    fn task_0 (&self, program: &int_code::IntCodeProgram) -> Result<Answer, DayError> {
+        let mut network = create_network(program);
+        Ok(Answer::from(network.do_task(true)?))
+    }
+    fn task_1 (&self, program: &int_code::IntCodeProgram) -> Result<Answer, DayError> {
+        let mut network = create_network(program);
+        Ok(Answer::from(network.do_task(false)?))
+    }
+}
+
+struct Network {
+    programs: Vec<(i128,int_code::IntCodeProgram)>
+}
+
+impl Network {
+    fn do_task (&mut self, is_task_0: bool) -> Result<i128, DayError> {
+        let mut packet_cache: HashMap<i128, (i128, Option<i128>, Option<i128>)> = HashMap::new();
+        let mut idle_map: HashMap<i128, bool> = HashMap::new();
+        let mut last_nat_packet_send_y = -1i128;
+        let mut nat_packet = (-1i128, -1i128);
+        loop {
+            for (_, program) in &self.programs {
+                program.check()?;
+            }
+            if self.programs.iter().all(|(_, program)| program.get_status() == int_code::IntCodeProgramStatus::Halt) {
+                return Err(DayError::vm_fault("all computers halted before the NAT got involved"));
+            }
+            for (i, program) in &mut self.programs {
+                let output_maybe = program.step();
+                if program.get_status() == int_code::IntCodeProgramStatus::WaitingForInput {
+                    idle_map.insert(*i, true);
+                    program.push_input(-1);
+                    program.step(); // output_maybe should be None because this step is an input instruction. Therefore no need to update.
+                }
+                if let Some(output) = output_maybe {
+                    let mut found = false;
+                    let mut item: Option<(i128, Option<i128>, Option<i128>)> = None;
+                    for (o_i, (address, x_maybe, _)) in packet_cache.iter() {
+                        if o_i == i {
+                            if let Some(x) = x_maybe {
+                                if *address == 255 && is_task_0 { return Ok(output); }
+                                else if *address == 255 {
+                                    trace!("{} -> NAT: ({}, {})", i, x, output);
+                                    nat_packet = (*x, output);
+                                }
+                                else if *address >= 0 && *address < 50 {
+                                    item = Some((*address, Some(*x), Some(output)));
+                                }
+                            }
+                            else {
+                                item = Some((*address, Some(output), None));
+                            }
+                            found = true;
+                        }
+                    } 
+                    if !found { packet_cache.insert(*i, (output, None, None)); }
+                    else if let Some(item) = item { packet_cache.insert(*i, item); }
+                }
+            }
+            let mut addresses_to_remove: Vec<i128> = Vec::new();
+            for (origin_address, (target_address, x_maybe, y_maybe)) in packet_cache.iter() {
+                if let (Some(x), Some(y)) = (x_maybe, y_maybe) {
+                    for (i, target_program) in &mut self.programs {
+                        if i == target_address {
+                            trace!("{} -> {}: ({}, {})", origin_address, target_address, x, y);
+                            target_program.push_input(*x);
+                            target_program.push_input(*y);
+                            idle_map.insert(*i, false);
+                        }
+                    }
+                    addresses_to_remove.push(*origin_address);
+                }
+            }
+            for address in addresses_to_remove {
+                packet_cache.remove(&address);
+            }
+            if idle_map.len() == 50 && idle_map.values().all(|b| *b) && nat_packet != (-1, -1) {
+                if last_nat_packet_send_y == nat_packet.1 { return Ok(last_nat_packet_send_y); }
+                debug!("the network is idle, the NAT wakes it up with ({}, {})", nat_packet.0, nat_packet.1);
+                if let Some((_, first_program)) = self.programs.get_mut(0) {
+                    first_program.push_input(nat_packet.0);
+                    first_program.push_input(nat_packet.1);
+                }
+                idle_map.insert(0, false);
+                last_nat_packet_send_y = nat_packet.1;
+            }
+        }
+    }
+}
+
+fn create_network (template: &int_code::IntCodeProgram) -> Network {
+    let mut programs: Vec<(i128,int_code::IntCodeProgram)> = Vec::new();
+    
+    for i in 0..50 {
+        let mut program = template.clone();
+        program.push_input(i);
+        programs.push((i, program));
+    }
+
+    Network { programs }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answer::Answer;
+    use crate::assembler;
+    use crate::day_tasks::{DayTasks, Part};
+
+    // 0 sends (3, 4) to 1, which sends (x, 10 * y) = (3, 40) to the NAT, and 0 answers every packet with (x, x) to the NAT
+    const COMPUTER: &str = "
+        IN -> [address]
+        JZ [address], first
+listen: IN -> [x]
+        EQ [x], #-1 -> [t]
+        JNZ [t], listen
+        IN -> [y]
+        JZ [address], answer
+        MUL [y], #10 -> [y]
+        OUT #255
+        OUT [x]
+        OUT [y]
+        JZ #0, listen
+first:  OUT #1
+        OUT #3
+        OUT #4
+        JZ #0, listen
+answer: OUT #255
+        OUT [x]
+        OUT [x]
+        JZ #0, listen
+address: .data 0
+x:      .data 0
+y:      .data 0
+t:      .data 0
+";
+
+    #[test]
+    fn routes_packets_through_the_nat () {
+        let program = Day23.parse(&assembler::assemble(COMPUTER).unwrap()).unwrap();
+        assert_eq!(Day23.task(Part::First, &program).unwrap(), Answer::from(40));
+        assert_eq!(Day23.task(Part::Second, &program).unwrap(), Answer::from(3));
+    }
+}
@@ -1,17 +1,29 @@
+use crate::answer::Answer;
+use crate::day_error::DayError;
 use crate::day_tasks;
+use crate::input;
 use std::collections::{HashSet, VecDeque};
 
 pub struct Day24;
 
 impl day_tasks::DayTasks for Day24 {
+    type Input = u32;
+
+    fn year (&self) -> String {
+        "2019".to_string()
+    }
     fn day_number (&self) -> String {
         "24".to_string()
     }
-    fn task_0 (&self, input: &String) -> String {
-        get_biodiversity_of_recurring_layout(parse_0(input)).to_string()
+    fn parse (&self, input: &str) -> Result<u32, DayError> {
+        parse(input)
+    }
+    fn task_0 (&self, layout: &u32) -> Result<Answer, DayError> {
+        Ok(Answer::from(get_biodiversity_of_recurring_layout(*layout)))
     }
-    fn task_1 (&self, input: &String) -> String {
-        get_bugs_count(parse_1(input)).to_string()
+    fn task_1 (&self, layout: &u32) -> Result<Answer, DayError> {
+        // the middle tile holds the recursive grid, so it never contains a bug itself
+        Ok(Answer::from(get_bugs_count(layout & !(1u32<<12))))
     }
 }
 
@@ -25,9 +37,9 @@ fn get_bugs_count (initial_layout: u32) -> i32 {
         let mut new_layouts: VecDeque<u32> = VecDeque::new();
 
         for i in 0..layouts.len() {
-            let higher = if i == 0 { 0u32 } else { *layouts.get(i - 1).expect("out of bounds") };
-            let current = layouts.get(i).expect("out of bounds");
-            let lower = if i == layouts.len() - 1 { 0u32 } else { *layouts.get(i + 1).expect("out of bounds") };
+            let higher = if i == 0 { 0u32 } else { layouts[i - 1] };
+            let current = layouts[i];
+            let lower = if i == layouts.len() - 1 { 0u32 } else { layouts[i + 1] };
 
             let mut new: u32 = 0;
             for i in 0..25 {
@@ -63,10 +75,10 @@ fn get_bugs_count (initial_layout: u32) -> i32 {
 
                 // lower layout
                 let adjacent_lower_indices = match (x, y) {
-                    (2, 1) => [1u32<<0, 1u32<<1, 1u32<<2, 1u32<<3, 1u32<<4],
+                    (2, 1) => [1u32, 1u32<<1, 1u32<<2, 1u32<<3, 1u32<<4],
                     (3, 2) => [1u32<<4, 1u32<<9, 1u32<<14, 1u32<<19, 1u32<<24],
                     (2, 3) => [1u32<<20, 1u32<<21, 1u32<<22, 1u32<<23, 1u32<<24],
-                    (1, 2) => [1u32<<0, 1u32<<5, 1u32<<10, 1u32<<15, 1u32<<20],
+                    (1, 2) => [1u32, 1u32<<5, 1u32<<10, 1u32<<15, 1u32<<20],
                     _ => [1u32<<12, 1u32<<12, 1u32<<12, 1u32<<12, 1u32<<12]
                 };
                 adjacent_alive_count += adjacent_lower_indices
@@ -74,15 +86,15 @@ fn get_bugs_count (initial_layout: u32) -> i32 {
                     .filter(|lower_bit_index| lower & **lower_bit_index == **lower_bit_index)
                     .count();
 
-                if is_bug_alive && adjacent_alive_count == 1 || !is_bug_alive && (adjacent_alive_count == 1 ||adjacent_alive_count == 2) {
+                if adjacent_alive_count == 1 || !is_bug_alive && adjacent_alive_count == 2 {
                     new += bit_index;
                 }
             }
             new_layouts.push_back(new);
         }
 
-        if new_layouts.front().map(|i| *i != 0).unwrap_or(false) { new_layouts.push_front(0) }
-        if new_layouts.back().map(|i| *i != 0).unwrap_or(false) { new_layouts.push_back(0) }
+        if new_layouts.front().is_some_and(|i| *i != 0) { new_layouts.push_front(0) }
+        if new_layouts.back().is_some_and(|i| *i != 0) { new_layouts.push_back(0) }
         layouts = new_layouts;
     }
     
@@ -115,7 +127,7 @@ fn get_biodiversity_of_recurring_layout (initial_layout: u32) -> u32 {
                 .filter(|(x, y)| { let bit_index = 1u32<<(*x + *y * 5); (current_layout & bit_index) == bit_index })
                 .count();
                 
-            if is_bug_alive && adjacent_alive_bug_count == 1 || !is_bug_alive && (adjacent_alive_bug_count == 1 ||adjacent_alive_bug_count == 2) {
+            if adjacent_alive_bug_count == 1 || !is_bug_alive && adjacent_alive_bug_count == 2 {
                 new_layout += bit_index;
             }
         }
@@ -129,20 +141,45 @@ fn get_biodiversity_of_recurring_layout (initial_layout: u32) -> u32 {
     }
 }
 
-fn parse_1 (input: &String) -> u32 {
-    input
-        .lines()
-        .flat_map(|line| line.chars())
-        .enumerate()
-        .map(|(i, c)| if i != 12 && c == '#' { 1u32<<i } else { 0u32 })
-        .sum()
+fn parse (input: &str) -> Result<u32, DayError> {
+    let rows = input::grid(input);
+    if rows.len() != 5 {
+        return Err(DayError::parse(rows.len().min(5) + 1, format!("expected 5 rows of tiles but got {}", rows.len())));
+    }
+    let mut layout = 0u32;
+    for (y, row) in rows.iter().enumerate() {
+        if row.len() != 5 {
+            return Err(DayError::parse(y + 1, format!("'{}' isn't 5 tiles wide", row.iter().collect::<String>())));
+        }
+        for (x, c) in row.iter().copied().enumerate() {
+            match c {
+                '#' => layout += 1u32<<(x + y * 5),
+                '.' => {},
+                _ => return Err(DayError::parse(y + 1, format!("'{}' is neither a bug nor an empty space", c)))
+            }
+        }
+    }
+    Ok(layout)
 }
 
-fn parse_0 (input: &String) -> u32 {
-    input
-        .lines()
-        .flat_map(|line| line.chars())
-        .enumerate()
-        .map(|(i, c)| if c == '#' { 1u32<<i } else { 0u32 })
-        .sum()
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_tasks::Part;
+    use crate::examples::{self, Example};
+
+    const EXAMPLES: &[Example] = &[
+        (concat!(
+            "....#\n",
+            "#..#.\n",
+            "#..##\n",
+            "..#..\n",
+            "#...."),
+            Part::First, "2129920")
+    ];
+
+    #[test]
+    fn worked_examples () {
+        examples::check(&Day24, EXAMPLES);
+    }
 }
@@ -0,0 +1,125 @@
+use crate::answer::Answer;
+use crate::day_error::DayError;
+use crate::day_tasks;
+use super::ascii_code;
+use super::int_code;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::HashSet;
+
+pub struct Day25;
+
+impl day_tasks::DayTasks for Day25 {
+    type Input = ascii_code::AsciiCodeProgram;
+
+    fn year (&self) -> String {
+        "2019".to_string()
+    }
+    fn day_number (&self) -> String {
+        "25".to_string()
+    }
+    fn parse (&self, input: &str) -> Result<ascii_code::AsciiCodeProgram, DayError> {
+        ascii_code::create_program(input)
+    }
+    fn task_0 (&self, program: &ascii_code::AsciiCodeProgram) -> Result<Answer, DayError> {
+        let mut program = create_program_with_items_collected(program);
+        let (text, _) = program.run_until_waiting();
+        program.check()?;
+        if program.get_status() == int_code::IntCodeProgramStatus::Halt {
+            find_password(&text)
+                .map(Answer::from)
+                .ok_or_else(|| DayError::unsolvable(format!("the droid didn't get through the security checkpoint\n{}", text.trim_end())))
+        }
+        else {
+            Ok(Answer::RequiresInteraction)
+        }
+    }
+    fn task_1 (&self, _: &ascii_code::AsciiCodeProgram) -> Result<Answer, DayError> {
+        Ok(Answer::from("There is no second puzzle on day 25"))
+    }
+    fn interact (&self, program: &ascii_code::AsciiCodeProgram, _: day_tasks::Part) -> Result<Answer, DayError> {
+        let mut program = create_program_with_items_collected(program);
+        let transcript = program.run_and_prompt_user_input(&(|input| {
+            let item = input.get(2..).unwrap_or_default().trim();
+            match input.chars().next() {
+                Some('n') => "north\n".to_string(),
+                Some('s') => "south\n".to_string(),
+                Some('w') => "west\n".to_string(),
+                Some('e') => "east\n".to_string(),
+                Some('i') => "inv\n".to_string(),
+                Some('t') => format!("take {}\n", item),
+                Some('d') => format!("drop {}\n", item),
+                _ => "\n".to_string()
+            }
+        }))?;
+        program.check()?;
+        Ok(find_password(&transcript).map(Answer::from).unwrap_or(Answer::RequiresInteraction))
+    }
+    fn is_interactive (&self) -> bool {
+        true
+    }
+}
+
+fn create_program_with_items_collected (program: &ascii_code::AsciiCodeProgram) -> ascii_code::AsciiCodeProgram {
+    let mut program = program.clone();
+
+    program.push_script_as_input("north\ntake candy cane\nsouth\nsouth\ntake fuel cell\nsouth\ntake manifold\nnorth\nnorth\n");
+    program.push_script_as_input("west\ntake mutex\nsouth\nsouth\ntake coin\nwest\ntake dehydrated water\nsouth\ntake prime number\nnorth\neast\nnorth\n");
+    program.push_script_as_input("east\ntake cake\nnorth\nwest\nsouth\nwest\n");
+    brute_force_weight_check(&mut program, &mut ["coin", "cake", "prime number", "mutex", "dehydrated water", "manifold", "fuel cell", "candy cane"].iter().map(|s| s.to_string()).collect());
+    program
+}
+
+fn find_password (text: &str) -> Option<i128> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new("typing (\\d+) on the keypad").unwrap();
+    }
+    RE
+        .captures(text)
+        .and_then(|captures| captures.get(1))
+        .and_then(|password| password.as_str().parse::<i128>().ok())
+}
+
+fn brute_force_weight_check (program: &mut ascii_code::AsciiCodeProgram, items: &mut HashSet<String>) {
+    let items_vec: Vec<String> = items.iter().map(|s| s.to_string()).collect();
+    for item in items_vec {
+        program.push_script_as_input(&format!("drop {}\nwest\n", item));
+        items.remove(&item);
+        brute_force_weight_check(program, items);
+        program.push_script_as_input(&format!("take {}\n", item));
+        items.insert(item);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assembler;
+    use crate::day_tasks::{DayTasks, Part};
+
+    // lets the droid in after the first command
+    const AIRLOCK: &str = r#"
+read:   IN -> [char]
+        EQ [char], #10 -> [done]
+        JZ [done], read
+        ARB #message
+print:  JZ [rb], end
+        OUT [rb]
+        ARB #1
+        JZ #0, print
+end:    HLT
+char:   .data 0
+done:   .data 0
+message: .string "You should be able to get in by typing 1234 on the keypad at the main airlock.\n"
+        .data 0
+"#;
+
+    #[test]
+    fn reads_the_password () {
+        let program = Day25.parse(&assembler::assemble(AIRLOCK).unwrap()).unwrap();
+        assert_eq!(Day25.task(Part::First, &program).unwrap(), Answer::from(1234));
+        // a droid that is never let in reads every command and waits for more
+        let program = Day25.parse("3,100,1106,0,0").unwrap();
+        assert_eq!(Day25.task(Part::First, &program).unwrap(), Answer::RequiresInteraction);
+    }
+}
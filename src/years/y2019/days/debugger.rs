@@ -0,0 +1,413 @@
+use super::disassembler;
+use super::int_code::{self, Instruction, IntCodeProgram, IntCodeProgramStatus, VmFault};
+use std::collections::BTreeSet;
+use std::convert::TryFrom;
+use std::fmt;
+use std::io::{self, BufRead, Write};
+
+/// Memory is shown in rows of this many cells.
+const MEMORY_ROW_LENGTH: usize = 8;
+/// `mem` shows at most this many cells at once.
+const MEMORY_SHOW_LIMIT: usize = 1024;
+
+pub const HELP: &str = "\
+step [N]             execute the next N instructions (default 1)
+continue             run until a breakpoint, a watchpoint, input is needed or the program stops
+break [ADDRESS|OP]   stop before the instruction at ADDRESS or any instruction OP (like IN), list all without argument
+delete ADDRESS|OP    remove a breakpoint
+watch ADDRESS        stop after an instruction writes to ADDRESS
+unwatch ADDRESS      remove a watchpoint
+mem ADDRESS [N]      show N cells of memory from ADDRESS (default 8, at most 1024)
+set ADDRESS VALUE    write VALUE to memory
+rb [VALUE]           show or set the relative base
+input [N ...]        push numbers to the input queue, show the queue without argument
+ascii TEXT           push the character codes of TEXT and a newline to the input queue
+output [ascii]       show the outputs so far, as text with ascii
+where                show the next instruction and the status
+help                 show this help
+quit                 leave the debugger";
+
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Debug)]
+pub enum Breakpoint {
+    /// Before the instruction at the address.
+    Address(usize),
+    /// Before any instruction of the kind.
+    OpCode(Instruction)
+}
+
+impl Breakpoint {
+    /// An address or a mnemonic like `IN`.
+    pub fn parse (text: &str) -> Option<Breakpoint> {
+        match text.parse::<usize>() {
+            Ok(address) => Some(Breakpoint::Address(address)),
+            Err(_) => disassembler::parse_mnemonic(text).map(Breakpoint::OpCode)
+        }
+    }
+}
+
+impl fmt::Display for Breakpoint {
+    fn fmt (&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Breakpoint::Address(address) => write!(f, "address {}", address),
+            Breakpoint::OpCode(instruction) => write!(f, "{}", disassembler::mnemonic(*instruction))
+        }
+    }
+}
+
+/// Why stepping or continuing returned.
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub enum Stop {
+    /// One instruction was executed and nothing else happened.
+    Stepped,
+    /// The next instruction has a breakpoint.
+    Breakpoint(Breakpoint),
+    /// The last instruction wrote to a watched address.
+    Watchpoint { address: usize, old: i128, new: i128 },
+    WaitingForInput,
+    Halted,
+    Faulted(VmFault)
+}
+
+impl fmt::Display for Stop {
+    fn fmt (&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Stop::Stepped => write!(f, "stepped"),
+            Stop::Breakpoint(breakpoint) => write!(f, "breakpoint on {}", breakpoint),
+            Stop::Watchpoint { address, old, new } => write!(f, "wrote {} to {}, it was {}", new, address, old),
+            Stop::WaitingForInput => write!(f, "waiting for input"),
+            Stop::Halted => write!(f, "halted"),
+            Stop::Faulted(fault) => write!(f, "faulted: {}", fault)
+        }
+    }
+}
+
+/// Runs a program under control of breakpoints and watchpoints. The program can be taken from a
+/// solution at any point of its run and handed back with `into_program`.
+#[derive(Clone)]
+pub struct Debugger {
+    program: IntCodeProgram,
+    breakpoints: BTreeSet<Breakpoint>,
+    watchpoints: BTreeSet<usize>
+}
+
+impl Debugger {
+    pub fn new (program: IntCodeProgram) -> Debugger {
+        Debugger { program, breakpoints: BTreeSet::new(), watchpoints: BTreeSet::new() }
+    }
+
+    pub fn program (&self) -> &IntCodeProgram {
+        &self.program
+    }
+
+    /// For inspecting and changing memory, the relative base and the input queue.
+    pub fn program_mut (&mut self) -> &mut IntCodeProgram {
+        &mut self.program
+    }
+
+    pub fn into_program (self) -> IntCodeProgram {
+        self.program
+    }
+
+    pub fn breakpoints (&self) -> &BTreeSet<Breakpoint> {
+        &self.breakpoints
+    }
+
+    pub fn watchpoints (&self) -> &BTreeSet<usize> {
+        &self.watchpoints
+    }
+
+    pub fn add_breakpoint (&mut self, breakpoint: Breakpoint) {
+        self.breakpoints.insert(breakpoint);
+    }
+
+    /// Whether there was such a breakpoint.
+    pub fn remove_breakpoint (&mut self, breakpoint: Breakpoint) -> bool {
+        self.breakpoints.remove(&breakpoint)
+    }
+
+    pub fn watch (&mut self, address: usize) {
+        self.watchpoints.insert(address);
+    }
+
+    /// Whether the address was watched.
+    pub fn unwatch (&mut self, address: usize) -> bool {
+        self.watchpoints.remove(&address)
+    }
+
+    /// Executes the next instruction, breakpoints don't stop a single step.
+    pub fn step (&mut self) -> Stop {
+        if let Some(stop) = self.stopped() {
+            return stop;
+        }
+        let watched = self.program.pending_write().filter(|address| self.watchpoints.contains(address));
+        let old = watched.map(|address| self.program.peek(address));
+        self.program.step();
+        match (self.stopped(), watched, old) {
+            (Some(stop), _, _) => stop,
+            (None, Some(address), Some(old)) => Stop::Watchpoint { address, old, new: self.program.peek(address) },
+            _ => Stop::Stepped
+        }
+    }
+
+    /// Runs until the next instruction has a breakpoint or something else stops the program.
+    /// The instruction it continues from is executed even if it has a breakpoint.
+    pub fn resume (&mut self) -> Stop {
+        loop {
+            let stop = self.step();
+            if stop != Stop::Stepped {
+                return stop;
+            }
+            if let Some(breakpoint) = self.breakpoint() {
+                return Stop::Breakpoint(breakpoint);
+            }
+        }
+    }
+
+    /// The breakpoint on the next instruction, if it has one.
+    pub fn breakpoint (&self) -> Option<Breakpoint> {
+        let instruction_pointer = self.program.get_instruction_pointer();
+        let instruction = int_code::decode(self.program.peek(instruction_pointer)).ok().map(|(instruction, _)| instruction);
+        self.breakpoints.iter().copied().find(|breakpoint| match breakpoint {
+            Breakpoint::Address(address) => *address == instruction_pointer,
+            Breakpoint::OpCode(op_code) => Some(*op_code) == instruction
+        })
+    }
+
+    fn stopped (&self) -> Option<Stop> {
+        match self.program.get_status() {
+            IntCodeProgramStatus::Ready => None,
+            IntCodeProgramStatus::WaitingForInput => Some(Stop::WaitingForInput),
+            IntCodeProgramStatus::Halt => Some(Stop::Halted),
+            IntCodeProgramStatus::Faulted(fault) => Some(Stop::Faulted(fault))
+        }
+    }
+
+    /// The next instruction with its address, or why there is none.
+    pub fn location (&self) -> String {
+        match self.stopped() {
+            Some(stop @ Stop::Halted) | Some(stop @ Stop::Faulted(_)) => stop.to_string(),
+            stop => {
+                let instruction_pointer = self.program.get_instruction_pointer();
+                let text = disassembler::instruction_at(self.program.memory(), instruction_pointer)
+                    .unwrap_or_else(|| format!("; {} doesn't decode", self.program.peek(instruction_pointer)));
+                match stop {
+                    Some(stop) => format!("{:>6}  {}  ({})", instruction_pointer, text, stop),
+                    None => format!("{:>6}  {}", instruction_pointer, text)
+                }
+            }
+        }
+    }
+
+    /// Executes one line of the REPL and returns what it prints, see `HELP` for the commands.
+    pub fn command (&mut self, line: &str) -> Result<String, String> {
+        let words = line.split_whitespace().collect::<Vec<&str>>();
+        let number = |index: usize| -> Result<i128, String> {
+            let word = words.get(index).ok_or_else(|| format!("'{}' needs more arguments, see help", words[0]))?;
+            word.parse::<i128>().map_err(|_| format!("'{}' is not a number", word))
+        };
+        let address = |index: usize| number(index).and_then(|value| {
+            usize::try_from(value).map_err(|_| format!("{} is not an address", value))
+        });
+        match words.as_slice() {
+            [] => Ok(String::new()),
+            ["s"] | ["step"] | ["s", _] | ["step", _] => {
+                let count = if words.len() > 1 { address(1)? } else { 1 };
+                let mut stop = Stop::Stepped;
+                for _ in 0..count {
+                    stop = self.step();
+                    if stop != Stop::Stepped { break; }
+                }
+                Ok(self.report(&stop))
+            }
+            ["c"] | ["continue"] => {
+                let stop = self.resume();
+                Ok(self.report(&stop))
+            }
+            ["b"] | ["break"] => Ok(self.list_breakpoints()),
+            ["b", target] | ["break", target] => {
+                let breakpoint = Breakpoint::parse(target).ok_or_else(|| format!("'{}' is not an address or op code", target))?;
+                self.add_breakpoint(breakpoint);
+                Ok(format!("breakpoint on {}", breakpoint))
+            }
+            ["delete", target] => {
+                let breakpoint = Breakpoint::parse(target).ok_or_else(|| format!("'{}' is not an address or op code", target))?;
+                if self.remove_breakpoint(breakpoint) { Ok(format!("removed breakpoint on {}", breakpoint)) }
+                else { Err(format!("there is no breakpoint on {}", breakpoint)) }
+            }
+            ["w", _] | ["watch", _] => {
+                let address = address(1)?;
+                self.watch(address);
+                Ok(format!("watching {}", address))
+            }
+            ["unwatch", _] => {
+                let address = address(1)?;
+                if self.unwatch(address) { Ok(format!("stopped watching {}", address)) }
+                else { Err(format!("{} isn't watched", address)) }
+            }
+            ["x", ..] | ["mem", ..] if words.len() <= 3 => {
+                let start = address(1)?;
+                let count = if words.len() > 2 { address(2)? } else { MEMORY_ROW_LENGTH };
+                Ok(self.show_memory(start, count))
+            }
+            ["set", _, _] => {
+                let (address, value) = (address(1)?, number(2)?);
+                let old = self.program.peek(address);
+                self.program.poke(address, value).map_err(|reason| reason.to_string())?;
+                Ok(format!("wrote {} to {}, it was {}", value, address, old))
+            }
+            ["rb"] => Ok(format!("relative base {}", self.program.get_relative_base())),
+            ["rb", _] => {
+                let relative_base = number(1)?;
+                self.program.set_relative_base(relative_base);
+                Ok(format!("relative base {}", relative_base))
+            }
+            ["input"] | ["in"] => Ok(format!("input queue: {}", join(self.program.get_input_queue().iter()))),
+            ["input", ..] | ["in", ..] => {
+                let values = (1..words.len()).map(&number).collect::<Result<Vec<i128>, String>>()?;
+                for value in &values {
+                    self.program.push_input(*value);
+                }
+                Ok(format!("input queue: {}", join(self.program.get_input_queue().iter())))
+            }
+            ["ascii", ..] => {
+                let text = line.trim_start()["ascii".len()..].trim();
+                for character in text.chars().chain(Some('\n')) {
+                    self.program.push_input(character as i128);
+                }
+                Ok(format!("pushed {} characters", text.chars().count() + 1))
+            }
+            ["output"] | ["out"] => Ok(format!("outputs: {}", join(self.program.get_outputs().iter()))),
+            ["output", "ascii"] | ["out", "ascii"] => Ok(ascii(self.program.get_outputs())),
+            ["where"] => Ok(self.location()),
+            ["help"] => Ok(HELP.to_string()),
+            [command, ..] => Err(format!("unknown command or arguments '{}', see help", command))
+        }
+    }
+
+    fn report (&self, stop: &Stop) -> String {
+        match stop {
+            Stop::Stepped | Stop::Halted | Stop::Faulted(_) | Stop::WaitingForInput => self.location(),
+            stop => format!("{}\n{}", stop, self.location())
+        }
+    }
+
+    fn list_breakpoints (&self) -> String {
+        let breakpoints = self.breakpoints.iter().map(|breakpoint| breakpoint.to_string()).collect::<Vec<String>>();
+        format!("breakpoints: {}\nwatchpoints: {}", breakpoints.join(", "), join(self.watchpoints.iter()))
+    }
+
+    fn show_memory (&self, start: usize, count: usize) -> String {
+        let end = start.saturating_add(count.min(MEMORY_SHOW_LIMIT));
+        (start..end)
+            .step_by(MEMORY_ROW_LENGTH)
+            .map(|row| {
+                let end = row.saturating_add(MEMORY_ROW_LENGTH).min(end);
+                format!("{:>6}  {}", row, join((row..end).map(|address| self.program.peek(address))))
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+fn join<T: ToString> (values: impl Iterator<Item=T>) -> String {
+    values.map(|value| value.to_string()).collect::<Vec<String>>().join(", ")
+}
+
+/// Outputs beyond ASCII are shown as numbers in brackets.
+fn ascii (outputs: &[i128]) -> String {
+    outputs
+        .iter()
+        .map(|output| match u8::try_from(*output) {
+            Ok(code) if code.is_ascii() => (code as char).to_string(),
+            _ => format!("[{}]", output)
+        })
+        .collect()
+}
+
+/// Reads commands from `input` until `quit` or the end of the input, and writes their results to `output`.
+pub fn repl<R: BufRead, W: Write> (debugger: &mut Debugger, input: R, mut output: W) -> io::Result<()> {
+    writeln!(output, "{}", debugger.location())?;
+    write!(output, "(debug) ")?;
+    output.flush()?;
+    for line in input.lines() {
+        let line = line?;
+        if matches!(line.trim(), "q" | "quit") {
+            break;
+        }
+        match debugger.command(&line) {
+            Ok(text) if text.is_empty() => {}
+            Ok(text) => writeln!(output, "{}", text)?,
+            Err(message) => writeln!(output, "error: {}", message)?
+        }
+        write!(output, "(debug) ")?;
+        output.flush()?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ascii_code, assembler};
+
+    fn debugger (source: &str) -> Debugger {
+        Debugger::new(int_code::create_program(&assembler::assemble(source).unwrap()).unwrap())
+    }
+
+    const COUNTDOWN: &str = "
+        IN -> [counter]
+loop:   OUT [counter]
+        ADD [counter], #-1 -> [counter]
+        JNZ [counter], loop
+        HLT
+counter: .data 0
+";
+
+    #[test]
+    fn stops_on_breakpoints_and_watchpoints () {
+        let mut debugger = debugger(COUNTDOWN);
+        assert_eq!(debugger.resume(), Stop::WaitingForInput);
+        debugger.program_mut().push_input(2);
+        debugger.add_breakpoint(Breakpoint::OpCode(Instruction::Output));
+        assert_eq!(debugger.resume(), Stop::Breakpoint(Breakpoint::OpCode(Instruction::Output)));
+        assert_eq!(debugger.program().get_instruction_pointer(), 2);
+        debugger.remove_breakpoint(Breakpoint::OpCode(Instruction::Output));
+        debugger.watch(12);
+        assert_eq!(debugger.resume(), Stop::Watchpoint { address: 12, old: 2, new: 1 });
+        debugger.add_breakpoint(Breakpoint::Address(11));
+        assert_eq!(debugger.resume(), Stop::Watchpoint { address: 12, old: 1, new: 0 });
+        assert_eq!(debugger.resume(), Stop::Breakpoint(Breakpoint::Address(11)));
+        assert_eq!(debugger.resume(), Stop::Halted);
+        assert_eq!(debugger.program().get_outputs(), &[2, 1]);
+    }
+
+    #[test]
+    fn inspects_and_changes_the_program () {
+        let mut debugger = debugger(COUNTDOWN);
+        assert_eq!(debugger.command("where"), Ok("     0  IN -> [12]".to_string()));
+        assert_eq!(debugger.command("input 3 4"), Ok("input queue: 3, 4".to_string()));
+        assert_eq!(debugger.command("step 2"), Ok("     4  ADD [12], #-1 -> [12]".to_string()));
+        assert_eq!(debugger.command("set 12 1"), Ok("wrote 1 to 12, it was 3".to_string()));
+        assert_eq!(debugger.command("mem 11 3"), Ok("    11  99, 1, 0".to_string()));
+        assert_eq!(debugger.command(&format!("mem 1 {}", usize::MAX)).map(|rows| rows.lines().count()), Ok(128));
+        assert_eq!(debugger.command(&format!("mem {} 9", usize::MAX - 1)), Ok(format!("{}  0", usize::MAX - 1)));
+        assert_eq!(debugger.command("rb -5"), Ok("relative base -5".to_string()));
+        assert_eq!(debugger.command("continue"), Ok("halted".to_string()));
+        assert_eq!(debugger.command("output"), Ok("outputs: 3".to_string()));
+        assert_eq!(debugger.command("input"), Ok("input queue: 4".to_string()));
+        assert_eq!(debugger.command("break"), Ok("breakpoints: \nwatchpoints: ".to_string()));
+        assert_eq!(debugger.command("watch"), Err("unknown command or arguments 'watch', see help".to_string()));
+        assert_eq!(debugger.command("set 1"), Err("unknown command or arguments 'set', see help".to_string()));
+    }
+
+    #[test]
+    fn debugs_the_program_of_an_ascii_program () {
+        let mut program = ascii_code::create_program("3,100,4,100,99").unwrap();
+        program.push_script_as_input("A");
+        let mut debugger = Debugger::new(program.int_code_program().clone());
+        repl(&mut debugger, "step\ninput 66\n".as_bytes(), io::sink()).unwrap();
+        *program.int_code_program_mut() = debugger.into_program();
+        assert_eq!(program.run_until_waiting(), ("A".to_string(), None));
+        assert_eq!(program.into_inner().get_input_queue(), &[66]);
+    }
+}
@@ -0,0 +1,245 @@
+use super::int_code::{self, Instruction, ParameterMode};
+use std::collections::BTreeSet;
+use std::fmt;
+
+/// Data cells are listed in rows of this many.
+const DATA_ROW_LENGTH: usize = 8;
+
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub enum Operand {
+    Position(i128),
+    Immediate(i128),
+    /// Relative to the relative base.
+    Relative(i128)
+}
+
+impl fmt::Display for Operand {
+    fn fmt (&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Operand::Position(address) => write!(f, "[{}]", address),
+            Operand::Immediate(value) => write!(f, "#{}", value),
+            Operand::Relative(offset) if *offset < 0 => write!(f, "[rb{}]", offset),
+            Operand::Relative(offset) => write!(f, "[rb+{}]", offset)
+        }
+    }
+}
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub enum Line {
+    Instruction { address: usize, cells: Vec<i128>, instruction: Instruction, operands: Vec<Operand> },
+    /// Cells which don't decode as an instruction and are probably data.
+    Data { address: usize, cells: Vec<i128> }
+}
+
+impl Line {
+    pub fn address (&self) -> usize {
+        match self {
+            Line::Instruction { address, .. } | Line::Data { address, .. } => *address
+        }
+    }
+
+    /// The address an instruction jumps to, if it is given in immediate mode.
+    pub fn jump_target (&self) -> Option<i128> {
+        match self {
+            Line::Instruction { instruction: Instruction::JumpIfTrue, operands, .. }
+                | Line::Instruction { instruction: Instruction::JumpIfFalse, operands, .. } => match operands[1] {
+                Operand::Immediate(target) => Some(target),
+                _ => None
+            },
+            _ => None
+        }
+    }
+}
+
+/// The decoded memory of a program. Jump targets given in immediate mode are labeled `L<address>` when a line starts
+/// there, targets inside a line stay immediate values.
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct Listing {
+    pub lines: Vec<Line>,
+    pub labels: BTreeSet<usize>
+}
+
+/// Decodes `memory` from the start, one instruction after the other. Cells which don't decode cleanly
+/// (unknown op codes or modes, writes in immediate mode, negative addresses, jumps out of the program)
+/// are listed as data and decoding resumes at the next cell. Decoding also starts afresh at every jump
+/// target, so data that happens to decode doesn't swallow the code following it.
+pub fn disassemble (memory: &[i128]) -> Listing {
+    let first_sweep = sweep(memory, &BTreeSet::new());
+    let lines = sweep(memory, &jump_targets(&first_sweep, memory.len()));
+    let starts = lines.iter().map(Line::address).collect::<BTreeSet<usize>>();
+    let labels = jump_targets(&lines, memory.len()).intersection(&starts).copied().collect();
+    Listing { lines, labels }
+}
+
+fn sweep (memory: &[i128], labels: &BTreeSet<usize>) -> Vec<Line> {
+    let mut lines: Vec<Line> = Vec::new();
+    let mut address = 0;
+    while address < memory.len() {
+        let decoded = decode_at(memory, address)
+            .filter(|(instruction, _)| labels.range(address + 1..address + 1 + instruction.parameter_count()).next().is_none());
+        match (decoded, lines.last_mut()) {
+            (Some((instruction, operands)), _) => {
+                let length = instruction.parameter_count() + 1;
+                lines.push(Line::Instruction { address, cells: memory[address..address + length].to_vec(), instruction, operands });
+                address += length;
+                continue;
+            }
+            (None, Some(Line::Data { cells, .. })) if cells.len() < DATA_ROW_LENGTH && !labels.contains(&address) => {
+                cells.push(memory[address]);
+            }
+            (None, _) => lines.push(Line::Data { address, cells: vec![memory[address]] })
+        }
+        address += 1;
+    }
+    lines
+}
+
+fn decode_at (memory: &[i128], address: usize) -> Option<(Instruction, Vec<Operand>)> {
+    let op_code = *memory.get(address)?;
+    let (instruction, modes) = int_code::decode(op_code).ok()?;
+    let parameter_count = instruction.parameter_count();
+    let values = memory.get(address + 1..address + 1 + parameter_count)?;
+    let operands = values
+        .iter()
+        .zip(modes.iter())
+        .map(|(value, mode)| match mode {
+            ParameterMode::Position => Operand::Position(*value),
+            ParameterMode::Immediate => Operand::Immediate(*value),
+            ParameterMode::Relative => Operand::Relative(*value)
+        })
+        .collect::<Vec<Operand>>();
+    let writes_immediate = instruction.writes() && matches!(operands.last(), Some(Operand::Immediate(_)));
+    let negative_address = operands.iter().any(|operand| matches!(operand, Operand::Position(address) if *address < 0));
+    let jumps_out = match instruction {
+        Instruction::JumpIfTrue | Instruction::JumpIfFalse =>
+            matches!(operands[1], Operand::Immediate(target) if target < 0 || target >= memory.len() as i128),
+        _ => false
+    };
+    if writes_immediate || negative_address || jumps_out { None } else { Some((instruction, operands)) }
+}
+
+fn jump_targets (lines: &[Line], memory_length: usize) -> BTreeSet<usize> {
+    lines
+        .iter()
+        .filter_map(Line::jump_target)
+        .filter(|target| (0..memory_length as i128).contains(target))
+        .map(|target| target as usize)
+        .collect()
+}
+
+pub fn mnemonic (instruction: Instruction) -> &'static str {
+    match instruction {
+        Instruction::Add => "ADD",
+        Instruction::Multiply => "MUL",
+        Instruction::Input => "IN",
+        Instruction::Output => "OUT",
+        Instruction::JumpIfTrue => "JNZ",
+        Instruction::JumpIfFalse => "JZ",
+        Instruction::LesserThan => "LT",
+        Instruction::Equals => "EQ",
+        Instruction::AdjustRelativeBase => "ARB",
+        Instruction::Halt => "HLT"
+    }
+}
+
+/// The instruction of a mnemonic, in any case.
+pub fn parse_mnemonic (text: &str) -> Option<Instruction> {
+    int_code::INSTRUCTIONS.iter().copied().find(|instruction| mnemonic(*instruction).eq_ignore_ascii_case(text))
+}
+
+/// The instruction at `address` like in a listing without labels, `None` if it doesn't decode cleanly.
+pub fn instruction_at (memory: &[i128], address: usize) -> Option<String> {
+    let (instruction, operands) = decode_at(memory, address)?;
+    let listing = Listing { lines: Vec::new(), labels: BTreeSet::new() };
+    let line = Line::Instruction { address, cells: Vec::new(), instruction, operands: operands.clone() };
+    Some(listing.instruction_text(&line, instruction, &operands))
+}
+
+impl fmt::Display for Listing {
+    fn fmt (&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for line in &self.lines {
+            if self.labels.contains(&line.address()) {
+                writeln!(f, "L{}:", line.address())?;
+            }
+            let (cells, text) = match line {
+                Line::Instruction { cells, instruction, operands, .. } => (cells, self.instruction_text(line, *instruction, operands)),
+                Line::Data { cells, .. } => (cells, "; probable data".to_string())
+            };
+            let cells = cells.iter().map(|cell| cell.to_string()).collect::<Vec<String>>().join(",");
+            writeln!(f, "{:>6}  {:<24}  {}", line.address(), cells, text)?;
+        }
+        Ok(())
+    }
+}
+
+impl Listing {
+    /// The listing as assembly without addresses and raw cells, probable data as `.data` directives.
+    /// The assembler turns it back into the memory it was disassembled from.
+    pub fn to_source (&self) -> String {
+        let mut source = String::new();
+        for line in &self.lines {
+            if self.labels.contains(&line.address()) {
+                source.push_str(&format!("L{}:\n", line.address()));
+            }
+            let text = match line {
+                Line::Instruction { instruction, operands, .. } => self.instruction_text(line, *instruction, operands),
+                Line::Data { cells, .. } => format!(".data {}", cells.iter().map(|cell| cell.to_string()).collect::<Vec<String>>().join(", "))
+            };
+            source.push_str(&format!("    {}\n", text));
+        }
+        source
+    }
+
+    /// `ADD [rb+3], #5 -> [100]`, with the written parameter after the arrow and labels for jump targets.
+    fn instruction_text (&self, line: &Line, instruction: Instruction, operands: &[Operand]) -> String {
+        let mut texts = operands.iter().map(|operand| operand.to_string()).collect::<Vec<String>>();
+        if let Some(target) = line.jump_target().filter(|target| *target >= 0 && self.labels.contains(&(*target as usize))) {
+            texts[1] = format!("L{}", target);
+        }
+        let destination = if instruction.writes() { texts.pop() } else { None };
+        let mut text = mnemonic(instruction).to_string();
+        if !texts.is_empty() {
+            text = format!("{} {}", text, texts.join(", "));
+        }
+        match destination {
+            Some(destination) => format!("{} -> {}", text, destination),
+            None => text
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn listing (text_code: &str) -> String {
+        disassemble(int_code::create_program(text_code).unwrap().memory()).to_string()
+    }
+
+    #[test]
+    fn lists_instructions_with_modes_and_labels () {
+        assert_eq!(listing("1101,3,5,100,109,-2,203,3,1006,100,0,99"), "\
+L0:
+     0  1101,3,5,100              ADD #3, #5 -> [100]
+     4  109,-2                    ARB #-2
+     6  203,3                     IN -> [rb+3]
+     8  1006,100,0                JZ [100], L0
+    11  99                        HLT
+");
+    }
+
+    #[test]
+    fn marks_cells_that_dont_decode_as_data () {
+        assert_eq!(listing("1105,1,7,42,-1,11101,0,99"), &"
+     0  1105,1,7                  JNZ #1, L7
+     3  42,-1,11101,0             ; probable data
+L7:
+     7  99                        HLT
+"[1..]);
+        // the VM faults on a mode for a parameter OUT doesn't have, so it isn't an instruction here either
+        assert_eq!(listing("10004,0,99"), &"
+     0  10004,0                   ; probable data
+     2  99                        HLT
+"[1..]);
+    }
+}
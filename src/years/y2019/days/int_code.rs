@@ -0,0 +1,593 @@
+use super::disassembler;
+use super::tracer::{self, TraceRecord, Tracer};
+use crate::cancellation;
+use crate::day_error::DayError;
+use crate::input;
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Clone)]
+pub struct IntCodeProgram {
+    int_code: Vec<i128>,
+    instruction_pointer: usize,
+    input: VecDeque<i128>,
+    output: Vec<i128>,
+    status: IntCodeProgramStatus,
+    relative_base: i128,
+    tracer: Option<Tracer>
+}
+
+/// The most memory cells a program may use. Addresses beyond it are faults, which keeps a corrupt
+/// program from allocating all the memory there is.
+pub const MEMORY_LIMIT: usize = 1 << 20;
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub enum IntCodeProgramStatus {
+    Ready,
+    WaitingForInput,
+    Halt,
+    /// The program executed something invalid and stopped, stepping it further does nothing.
+    Faulted(VmFault)
+}
+
+/// Where and why a program faulted.
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct VmFault {
+    pub instruction_pointer: usize,
+    /// The raw op code including the parameter modes.
+    pub op_code: i128,
+    pub reason: FaultReason
+}
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub enum FaultReason {
+    UnknownOpCode,
+    /// `parameter` is 1-based.
+    UnknownParameterMode { parameter: usize, mode: i128 },
+    /// The parameter an instruction writes to is in immediate mode.
+    ImmediateWrite { parameter: usize },
+    NegativeAddress(i128),
+    /// The address is beyond `MEMORY_LIMIT`.
+    AddressOutOfRange(i128),
+    /// A sum, product or relative address doesn't fit into an `i128`.
+    Overflow,
+    /// The runner gave up on the worker running the program.
+    Cancelled
+}
+
+impl fmt::Display for FaultReason {
+    fn fmt (&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FaultReason::UnknownOpCode => write!(f, "unknown op code"),
+            FaultReason::UnknownParameterMode { parameter, mode } => write!(f, "unknown mode {} of parameter {}", mode, parameter),
+            FaultReason::ImmediateWrite { parameter } => write!(f, "parameter {} is written to but in immediate mode", parameter),
+            FaultReason::NegativeAddress(address) => write!(f, "negative address {}", address),
+            FaultReason::AddressOutOfRange(address) => write!(f, "address {} is beyond the memory limit of {} cells", address, MEMORY_LIMIT),
+            FaultReason::Overflow => write!(f, "arithmetic overflow"),
+            FaultReason::Cancelled => write!(f, "cancelled")
+        }
+    }
+}
+
+impl fmt::Display for VmFault {
+    fn fmt (&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} at address {} (op code {})", self.reason, self.instruction_pointer, self.op_code)
+    }
+}
+
+impl Error for VmFault {}
+
+impl From<VmFault> for DayError {
+    fn from (fault: VmFault) -> DayError {
+        DayError::vm_fault(fault)
+    }
+}
+
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub enum ParameterMode {
+    Position,
+    Immediate,
+    Relative
+}
+
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Debug)]
+pub enum Instruction {
+    Add,
+    Multiply,
+    Input,
+    Output,
+    JumpIfTrue,
+    JumpIfFalse,
+    LesserThan,
+    Equals,
+    AdjustRelativeBase,
+    Halt
+}
+
+impl Instruction {
+    /// The number of parameters following the op code.
+    pub fn parameter_count (self) -> usize {
+        match self {
+            Instruction::Add | Instruction::Multiply | Instruction::LesserThan | Instruction::Equals => 3,
+            Instruction::JumpIfTrue | Instruction::JumpIfFalse => 2,
+            Instruction::Input | Instruction::Output | Instruction::AdjustRelativeBase => 1,
+            Instruction::Halt => 0
+        }
+    }
+
+    /// Whether the last parameter is the address the instruction writes to.
+    pub fn writes (self) -> bool {
+        matches!(self, Instruction::Add | Instruction::Multiply | Instruction::LesserThan | Instruction::Equals | Instruction::Input)
+    }
+
+    /// The op code with all parameters in position mode.
+    pub fn op_code (self) -> i128 {
+        match self {
+            Instruction::Add => 1,
+            Instruction::Multiply => 2,
+            Instruction::Input => 3,
+            Instruction::Output => 4,
+            Instruction::JumpIfTrue => 5,
+            Instruction::JumpIfFalse => 6,
+            Instruction::LesserThan => 7,
+            Instruction::Equals => 8,
+            Instruction::AdjustRelativeBase => 9,
+            Instruction::Halt => 99
+        }
+    }
+}
+
+pub const INSTRUCTIONS: [Instruction; 10] = [
+    Instruction::Add, Instruction::Multiply, Instruction::Input, Instruction::Output, Instruction::JumpIfTrue,
+    Instruction::JumpIfFalse, Instruction::LesserThan, Instruction::Equals, Instruction::AdjustRelativeBase, Instruction::Halt
+];
+
+/// Splits an op code into its instruction and the modes of the three parameters it may have. Only the
+/// instruction's own parameters may have a mode other than position mode, every digit above them has to be 0.
+/// The disassembler decodes with the same rule, so what it lists as an instruction runs as one.
+pub fn decode (op_code: i128) -> Result<(Instruction, [ParameterMode; 3]), FaultReason> {
+    fn parse_parameter_mode (op_code: i128, parameter: usize) -> Result<ParameterMode, FaultReason> {
+        match (op_code / 10i128.pow(parameter as u32 + 1)) % 10 {
+            0 => Ok(ParameterMode::Position),
+            1 => Ok(ParameterMode::Immediate),
+            2 => Ok(ParameterMode::Relative),
+            mode => Err(FaultReason::UnknownParameterMode { parameter, mode })
+        }
+    }
+    let instruction = match op_code % 100 {
+        _ if op_code < 0 => return Err(FaultReason::UnknownOpCode),
+        1 => Instruction::Add,
+        2 => Instruction::Multiply,
+        3 => Instruction::Input,
+        4 => Instruction::Output,
+        5 => Instruction::JumpIfTrue,
+        6 => Instruction::JumpIfFalse,
+        7 => Instruction::LesserThan,
+        8 => Instruction::Equals,
+        9 => Instruction::AdjustRelativeBase,
+        99 => Instruction::Halt,
+        _ => return Err(FaultReason::UnknownOpCode)
+    };
+    let parameter_count = instruction.parameter_count();
+    let mut modes = [ParameterMode::Position; 3];
+    for (index, mode) in modes.iter_mut().enumerate().take(parameter_count) {
+        *mode = parse_parameter_mode(op_code, index + 1)?;
+    }
+    let mut extra_modes = op_code / 10i128.pow(parameter_count as u32 + 2);
+    let mut parameter = parameter_count + 1;
+    while extra_modes != 0 {
+        if extra_modes % 10 != 0 {
+            return Err(FaultReason::UnknownParameterMode { parameter, mode: extra_modes % 10 });
+        }
+        extra_modes /= 10;
+        parameter += 1;
+    }
+    Ok((instruction, modes))
+}
+
+impl IntCodeProgram {
+    pub fn run_until_stopped (&mut self) {
+        while self.status == IntCodeProgramStatus::Ready {
+            self.step();
+        }
+    }
+    
+    pub fn run_until_next_output (&mut self) -> Option<i128> {
+        let mut current_output = None;
+        while self.status == IntCodeProgramStatus::Ready && current_output.is_none() {
+            current_output = self.step();
+        }
+        current_output
+    }
+
+    /// The program's memory as far as it has been used, starting with the code it was created from.
+    pub fn memory (&self) -> &[i128] {
+        &self.int_code
+    }
+
+    pub fn get_last_output (&self) -> Option<i128> {
+        self.output.last().copied()
+    }
+
+    pub fn get_outputs (&self) -> &[i128] {
+        &self.output
+    }
+
+    /// The inputs pushed but not read yet.
+    pub fn get_input_queue (&self) -> &VecDeque<i128> {
+        &self.input
+    }
+
+    pub fn get_instruction_pointer (&self) -> usize {
+        self.instruction_pointer
+    }
+
+    pub fn get_relative_base (&self) -> i128 {
+        self.relative_base
+    }
+
+    pub fn set_relative_base (&mut self, relative_base: i128) {
+        self.relative_base = relative_base;
+    }
+
+    /// The value at `position`, memory that wasn't used yet reads as 0.
+    pub fn peek (&self, position: usize) -> i128 {
+        self.int_code.get(position).copied().unwrap_or(0)
+    }
+
+    pub fn poke (&mut self, position: usize, value: i128) -> Result<(), FaultReason> {
+        let position = address(position as i128)?;
+        self.set_int_code(position, value);
+        Ok(())
+    }
+
+    /// The address the next instruction writes to, `None` if it doesn't write, would fault or waits for input.
+    pub fn pending_write (&self) -> Option<usize> {
+        if self.status != IntCodeProgramStatus::Ready {
+            return None;
+        }
+        let (instruction, modes) = decode(self.peek(self.instruction_pointer)).ok()?;
+        if !instruction.writes() || instruction == Instruction::Input && self.input.is_empty() {
+            return None;
+        }
+        let parameter = instruction.parameter_count();
+        let value = self.peek(self.instruction_pointer + parameter);
+        match modes[parameter - 1] {
+            ParameterMode::Position => address(value).ok(),
+            ParameterMode::Relative => relative_address(self.relative_base, value).ok(),
+            ParameterMode::Immediate => None
+        }
+    }
+
+    pub fn get_status (&self) -> IntCodeProgramStatus {
+        self.status.clone()
+    }
+
+    pub fn push_input (&mut self, input: i128) {
+        self.input.push_back(input);
+        if self.status == IntCodeProgramStatus::WaitingForInput {
+            self.status = IntCodeProgramStatus::Ready }
+    }
+
+    pub fn day_02_initialize (&mut self, noun: i128, verb: i128) -> Result<(), DayError> {
+        self.patch(1, noun)?;
+        self.patch(2, verb)
+    }
+
+    pub fn day_02_result (&self) -> i128 {
+        self.peek(0)
+    }
+
+    pub fn day_13_part_two_initialize (&mut self) -> Result<(), DayError> {
+        self.patch(0, 2)
+    }
+
+    pub fn day_17_part_two_initialize (&mut self) -> Result<(), DayError> {
+        self.patch(0, 2)
+    }
+
+    /// Changes the program's own code, a program too short to contain `position` can't be what the day expects.
+    fn patch (&mut self, position: usize, value: i128) -> Result<(), DayError> {
+        let cell = self.int_code
+            .get_mut(position)
+            .ok_or_else(|| DayError::parse(1, format!("the program is too short to patch address {}", position)))?;
+        *cell = value;
+        Ok(())
+    }
+
+    pub fn step (&mut self) -> Option<i128> {
+        if matches!(self.status, IntCodeProgramStatus::Halt | IntCodeProgramStatus::Faulted(_)) {
+            return None;
+        }
+        let instruction_pointer = self.instruction_pointer;
+        if cancellation::is_cancelled() {
+            // nobody waits for the program any more, its trace would only grow
+            self.tracer = None;
+            let op_code = self.peek(instruction_pointer);
+            self.status = IntCodeProgramStatus::Faulted(VmFault { instruction_pointer, op_code, reason: FaultReason::Cancelled });
+            return None;
+        }
+        let trace_record = self.begin_trace();
+        match self.execute() {
+            Ok(output) => {
+                if let Some(record) = trace_record {
+                    self.finish_trace(record);
+                }
+                output
+            }
+            Err(reason) => {
+                if let Some(mut record) = trace_record {
+                    record.write = None;
+                    record.fault = Some(reason.clone());
+                    self.finish_trace(record);
+                }
+                let op_code = self.int_code.get(instruction_pointer).copied().unwrap_or(0);
+                self.status = IntCodeProgramStatus::Faulted(VmFault { instruction_pointer, op_code, reason });
+                None
+            }
+        }
+    }
+
+    /// Traces every instruction the program executes from now on, `None` stops tracing.
+    pub fn set_tracer (&mut self, tracer: Option<Tracer>) {
+        self.tracer = tracer;
+    }
+
+    /// The record of the next instruction before it is executed, `None` if it isn't traced or waits for input.
+    /// An instruction that will fault is recorded with the values it reads up to the faulting one.
+    fn begin_trace (&self) -> Option<TraceRecord> {
+        let instruction_pointer = self.instruction_pointer;
+        if !self.tracer.as_ref()?.traces(instruction_pointer) {
+            return None;
+        }
+        let reads = match decode(self.peek(instruction_pointer)) {
+            Ok((Instruction::Input, _)) => vec![*self.input.front()?],
+            Ok((instruction, modes)) => {
+                let read_count = instruction.parameter_count() - if instruction.writes() { 1 } else { 0 };
+                (0..read_count)
+                    .map_while(|index| self.peek_parameter(instruction_pointer + 1 + index, modes[index]))
+                    .collect()
+            }
+            Err(_) => Vec::new()
+        };
+        Some(TraceRecord {
+            instruction_pointer,
+            instruction: disassembler::instruction_at(&self.int_code, instruction_pointer)
+                .unwrap_or_else(|| self.peek(instruction_pointer).to_string()),
+            relative_base: self.relative_base,
+            reads,
+            write: self.pending_write().map(|address| (address, 0)),
+            fault: None
+        })
+    }
+
+    fn finish_trace (&self, mut record: TraceRecord) {
+        if let Some(tracer) = &self.tracer {
+            record.write = record.write.map(|(address, _)| (address, self.peek(address)));
+            tracer.record(&record);
+        }
+    }
+
+    /// The value of a parameter without growing the memory, `None` if reading it faults.
+    fn peek_parameter (&self, position: usize, parameter_mode: ParameterMode) -> Option<i128> {
+        let operator = self.peek(position);
+        match parameter_mode {
+            ParameterMode::Position => address(operator).ok().map(|position| self.peek(position)),
+            ParameterMode::Immediate => Some(operator),
+            ParameterMode::Relative => relative_address(self.relative_base, operator).ok().map(|position| self.peek(position))
+        }
+    }
+
+    /// Fails with the fault of the program, if it faulted.
+    pub fn check (&self) -> Result<(), VmFault> {
+        match &self.status {
+            IntCodeProgramStatus::Faulted(fault) => Err(fault.clone()),
+            _ => Ok(())
+        }
+    }
+
+    /// Explains why the program stopped before doing what was expected of it:
+    /// with its fault if it faulted, with `message` if it just halted or waits for input.
+    pub fn unexpected_stop (&self, message: &str) -> DayError {
+        match self.check() {
+            Err(fault) => DayError::from(fault),
+            Ok(()) => DayError::vm_fault(message)
+        }
+    }
+
+    fn execute (&mut self) -> Result<Option<i128>, FaultReason> {
+        let op_code = self.get_int_code(self.instruction_pointer);
+        let (instruction, [mode_0, mode_1, mode_2]) = decode(op_code)?;
+    
+        match instruction {
+            Instruction::Add | Instruction::Multiply | Instruction::JumpIfTrue | Instruction::JumpIfFalse | Instruction::LesserThan | Instruction::Equals => {
+                let operator_0 = self.fetch_operator(self.instruction_pointer + 1, mode_0)?;
+                let operator_1 = self.fetch_operator(self.instruction_pointer + 2, mode_1)?;
+    
+                match instruction {
+                    Instruction::JumpIfTrue | Instruction::JumpIfFalse => {
+                        if instruction == Instruction::JumpIfTrue && operator_0 != 0 || instruction == Instruction::JumpIfFalse && operator_0 == 0 
+                        { self.instruction_pointer = address(operator_1)? } 
+                        else { self.instruction_pointer += 3 }
+                    }
+                    _ => {
+                        let target_index = self.get_parameter_position(self.instruction_pointer + 3, mode_2, 3)?;
+                        let result = match instruction {
+                            Instruction::Add => operator_0.checked_add(operator_1).ok_or(FaultReason::Overflow)?,
+                            Instruction::Multiply => operator_0.checked_mul(operator_1).ok_or(FaultReason::Overflow)?,
+                            Instruction::LesserThan => if operator_0 < operator_1 { 1 } else { 0 },
+                            _ => if operator_0 == operator_1 { 1 } else { 0 } // Should be op code Instruction::Equals
+                        };
+                        self.set_int_code(target_index, result);
+                        
+                        self.instruction_pointer += 4;}
+                }
+                Ok(None)
+            }
+            Instruction::Input => {
+                if !self.input.is_empty() {
+                    let target_index = self.get_parameter_position(self.instruction_pointer + 1, mode_0, 1)?;
+                    let input = self.input.pop_front().unwrap();
+                    self.set_int_code(target_index, input);
+                    self.instruction_pointer += 2;
+                }
+                else {
+                    self.status = IntCodeProgramStatus::WaitingForInput;
+                }
+                Ok(None)
+            }
+            Instruction::Output => {
+                let operator_0 = self.fetch_operator(self.instruction_pointer + 1, mode_0)?;
+
+                self.output.push(operator_0);
+                self.instruction_pointer += 2;
+                
+                Ok(Some(operator_0))
+            },
+            Instruction::AdjustRelativeBase => {
+                let operator_0 = self.fetch_operator(self.instruction_pointer + 1, mode_0)?;
+
+                self.relative_base = self.relative_base.checked_add(operator_0).ok_or(FaultReason::Overflow)?;
+                self.instruction_pointer += 2;
+                
+                Ok(None)
+            },
+            Instruction::Halt => {
+                self.status = IntCodeProgramStatus::Halt;
+                self.instruction_pointer = self.int_code.len();
+                Ok(None)
+            }
+        }
+    }
+
+    fn allocate_space_if_necessary (&mut self, until_position: usize)
+    {
+        if until_position >= self.int_code.len() {
+            for _ in self.int_code.len()..=until_position {
+                self.int_code.push(0);
+            }
+        }
+    }
+
+    fn get_int_code(&mut self, position: usize) -> i128 {
+        self.allocate_space_if_necessary(position);
+        self.int_code[position]
+    }
+
+    fn set_int_code(&mut self, position: usize, value: i128) {
+        self.allocate_space_if_necessary(position);
+        self.int_code[position] = value;
+    }
+
+    fn fetch_operator (&mut self, initial_position: usize, parameter_mode: ParameterMode) -> Result<i128, FaultReason> {
+        let operator = self.get_int_code(initial_position);
+        match parameter_mode {
+            ParameterMode::Position => Ok(self.get_int_code(address(operator)?)),
+            ParameterMode::Immediate => Ok(operator),
+            ParameterMode::Relative => Ok(self.get_int_code(relative_address(self.relative_base, operator)?))
+        }
+    }
+
+    /// `parameter` is the 1-based number of the parameter, for the fault.
+    fn get_parameter_position (&mut self, initial_position: usize, parameter_mode: ParameterMode, parameter: usize) -> Result<usize, FaultReason> {
+        let operator = self.get_int_code(initial_position);
+        match parameter_mode {
+            ParameterMode::Position => address(operator),
+            ParameterMode::Relative => relative_address(self.relative_base, operator),
+            ParameterMode::Immediate => Err(FaultReason::ImmediateWrite { parameter })
+        }
+    }
+}
+
+/// Checks that `value` can be used as an address, the memory grows up to `MEMORY_LIMIT` on demand.
+fn address (value: i128) -> Result<usize, FaultReason> {
+    if value < 0 { Err(FaultReason::NegativeAddress(value)) }
+    else if value >= MEMORY_LIMIT as i128 { Err(FaultReason::AddressOutOfRange(value)) }
+    else { Ok(value as usize) }
+}
+
+fn relative_address (relative_base: i128, offset: i128) -> Result<usize, FaultReason> {
+    address(relative_base.checked_add(offset).ok_or(FaultReason::Overflow)?)
+}
+
+pub fn create_program (text_code: &str) -> Result<IntCodeProgram, DayError> {
+    Ok(IntCodeProgram { 
+        int_code: input::csv_ints(text_code)?, 
+        instruction_pointer: 0,
+        input: VecDeque::new(),
+        output: Vec::new(), 
+        status: IntCodeProgramStatus::Ready,
+        relative_base: 0,
+        tracer: tracer::for_new_program() })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fault_of (text_code: &str) -> VmFault {
+        let mut program = create_program(text_code).unwrap();
+        program.run_until_stopped();
+        match program.get_status() {
+            IntCodeProgramStatus::Faulted(fault) => fault,
+            status => panic!("expected a fault but the program stopped with {:?}", status)
+        }
+    }
+
+    #[test]
+    fn halts_on_99 () {
+        let mut program = create_program("1,0,0,0,99").unwrap();
+        program.run_until_stopped();
+        assert_eq!(program.get_status(), IntCodeProgramStatus::Halt);
+        assert_eq!(program.check(), Ok(()));
+        assert_eq!(program.day_02_result(), 2);
+    }
+
+    #[test]
+    fn rejects_patches_beyond_the_program () {
+        let mut program = create_program("1,0").unwrap();
+        assert_eq!(
+            program.day_02_initialize(12, 2),
+            Err(DayError::parse(1, "the program is too short to patch address 2")));
+        assert_eq!(create_program("99").unwrap().day_13_part_two_initialize(), Ok(()));
+    }
+
+    #[test]
+    fn relative_offsets_may_be_negative () {
+        // ARB #5; OUT [rb - 1]; HLT, with the output cell right before the relative base
+        let mut program = create_program("109,5,204,-1,99").unwrap();
+        assert_eq!(program.run_until_next_output(), Some(99));
+        assert_eq!(program.check(), Ok(()));
+    }
+
+    #[test]
+    fn faults_on_invalid_instructions () {
+        assert_eq!(fault_of("1,0,0,0,42"), VmFault { instruction_pointer: 4, op_code: 42, reason: FaultReason::UnknownOpCode });
+        assert_eq!(fault_of("-1"), VmFault { instruction_pointer: 0, op_code: -1, reason: FaultReason::UnknownOpCode });
+        assert_eq!(fault_of("301,0,0,0,99").reason, FaultReason::UnknownParameterMode { parameter: 1, mode: 3 });
+        assert_eq!(fault_of("10001,0,0,0,99").reason, FaultReason::ImmediateWrite { parameter: 3 });
+        assert_eq!(fault_of("10004,0,99").reason, FaultReason::UnknownParameterMode { parameter: 3, mode: 1 });
+        assert_eq!(fault_of("100000099").reason, FaultReason::UnknownParameterMode { parameter: 7, mode: 1 });
+        assert_eq!(fault_of("1,-5,0,0,99").reason, FaultReason::NegativeAddress(-5));
+        assert_eq!(fault_of("1105,1,-3").reason, FaultReason::NegativeAddress(-3));
+        assert_eq!(fault_of("209,-7,204,0,99").reason, FaultReason::NegativeAddress(-7));
+        assert_eq!(fault_of("4,100000000000,99").reason, FaultReason::AddressOutOfRange(100_000_000_000));
+        let max = i128::MAX;
+        assert_eq!(fault_of(&format!("1101,{},1,0,99", max)).reason, FaultReason::Overflow);
+        assert_eq!(fault_of(&format!("1102,{},2,0,99", max)).reason, FaultReason::Overflow);
+        assert_eq!(fault_of(&format!("109,{},109,1,99", max)).reason, FaultReason::Overflow);
+        assert_eq!(fault_of(&format!("109,{},204,1,99", max)).reason, FaultReason::Overflow);
+    }
+
+    #[test]
+    fn faulted_programs_stay_faulted () {
+        let mut program = create_program("4,0,42").unwrap();
+        assert_eq!(program.run_until_next_output(), Some(4));
+        assert_eq!(program.run_until_next_output(), None);
+        assert_eq!(program.step(), None);
+        assert_eq!(
+            program.unexpected_stop("no output").to_string(),
+            "IntCode fault: unknown op code at address 2 (op code 42)");
+    }
+}
@@ -0,0 +1,64 @@
+use crate::day_tasks::Solution;
+use std::sync::Arc;
+
+pub mod day_01;
+pub mod day_02;
+pub mod day_03;
+pub mod day_04;
+pub mod day_05;
+pub mod day_06;
+pub mod day_07;
+pub mod day_08;
+pub mod day_09;
+pub mod day_10;
+pub mod day_11;
+pub mod day_12;
+pub mod day_13;
+pub mod day_14;
+pub mod day_15;
+pub mod day_16;
+pub mod day_17;
+pub mod day_18;
+pub mod day_19;
+pub mod day_20;
+pub mod day_21;
+pub mod day_22;
+pub mod day_23;
+pub mod day_24;
+pub mod day_25;
+pub mod int_code;
+pub mod ascii_code;
+pub mod disassembler;
+pub mod assembler;
+pub mod debugger;
+pub mod tracer;
+
+pub fn all () -> Vec<Arc<dyn Solution>> {
+    vec![
+        Arc::new(day_01::Day01),
+        Arc::new(day_02::Day02),
+        Arc::new(day_03::Day03),
+        Arc::new(day_04::Day04),
+        Arc::new(day_05::Day05),
+        Arc::new(day_06::Day06),
+        Arc::new(day_07::Day07),
+        Arc::new(day_08::Day08),
+        Arc::new(day_09::Day09),
+        Arc::new(day_10::Day10),
+        Arc::new(day_11::Day11),
+        Arc::new(day_12::Day12),
+        Arc::new(day_13::Day13),
+        Arc::new(day_14::Day14),
+        Arc::new(day_15::Day15),
+        Arc::new(day_16::Day16),
+        Arc::new(day_17::Day17),
+        Arc::new(day_18::Day18),
+        Arc::new(day_19::Day19),
+        Arc::new(day_20::Day20),
+        Arc::new(day_21::Day21),
+        Arc::new(day_22::Day22),
+        Arc::new(day_23::Day23),
+        Arc::new(day_24::Day24),
+        Arc::new(day_25::Day25),
+    ]
+}
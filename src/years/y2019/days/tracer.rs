@@ -0,0 +1,225 @@
+use super::int_code::FaultReason;
+use crate::cancellation;
+use lazy_static::lazy_static;
+use std::fmt;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::ops::RangeInclusive;
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+lazy_static! {
+    /// Every program created while it is set is traced by a clone of it.
+    static ref TRACE_ALL: Mutex<Option<Tracer>> = Mutex::new(None);
+}
+
+/// One executed instruction, as the program was right before executing it.
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct TraceRecord {
+    pub instruction_pointer: usize,
+    /// The instruction like in a disassembly, the raw op code if it doesn't decode cleanly.
+    pub instruction: String,
+    pub relative_base: i128,
+    /// The values of the parameters read, for `IN` the input.
+    pub reads: Vec<i128>,
+    /// The address written to and the value written.
+    pub write: Option<(usize, i128)>,
+    /// Why the instruction faulted instead of executing.
+    pub fault: Option<FaultReason>
+}
+
+/// `12: ADD [rb+3], #5 -> [100] | rb=10 read=7,5 write=[100]=12`, a faulting instruction ends in `fault=<reason>`
+impl fmt::Display for TraceRecord {
+    fn fmt (&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {} | rb={}", self.instruction_pointer, self.instruction, self.relative_base)?;
+        if !self.reads.is_empty() {
+            write!(f, " read={}", self.reads.iter().map(|value| value.to_string()).collect::<Vec<String>>().join(","))?;
+        }
+        if let Some((address, value)) = self.write {
+            write!(f, " write=[{}]={}", address, value)?;
+        }
+        if let Some(fault) = &self.fault {
+            write!(f, " fault={}", fault)?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes one line per executed instruction of a program, prefixed with the program's number, as long as the
+/// instruction's address is in `addresses`. Clones write to the same sink under the number of their parent followed
+/// by how many clones the parent made so far, `2.3` for the third clone of program 2. So programs cloned from a traced
+/// one, like the computers of a network, can be told apart, and a number only depends on the program's own ancestors,
+/// which keeps the traces of two runs comparable with `diff`.
+pub struct Tracer {
+    program: String,
+    addresses: RangeInclusive<usize>,
+    clones: AtomicUsize,
+    sink: Arc<Mutex<dyn Write + Send>>
+}
+
+impl Tracer {
+    pub fn new<W: Write + Send + 'static> (sink: W, addresses: RangeInclusive<usize>) -> Tracer {
+        Tracer { program: "0".to_string(), addresses, clones: AtomicUsize::new(0), sink: Arc::new(Mutex::new(sink)) }
+    }
+
+    pub fn to_file (path: &Path, addresses: RangeInclusive<usize>) -> Result<Tracer, String> {
+        let file = File::create(path).map_err(|error| format!("couldn't create trace '{}': {}", path.display(), error))?;
+        Ok(Tracer::new(BufWriter::new(file), addresses))
+    }
+
+    pub fn program (&self) -> &str {
+        &self.program
+    }
+
+    pub fn traces (&self, address: usize) -> bool {
+        self.addresses.contains(&address)
+    }
+
+    pub fn record (&self, record: &TraceRecord) {
+        let mut sink = self.sink.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        // a trace that can't be written is no reason to stop the program, flush reports the error
+        let _ = writeln!(sink, "{} {}", self.program, record);
+    }
+
+    pub fn flush (&self) -> Result<(), String> {
+        let mut sink = self.sink.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        sink.flush().map_err(|error| format!("couldn't write the trace: {}", error))
+    }
+}
+
+impl Clone for Tracer {
+    fn clone (&self) -> Tracer {
+        let clone = self.clones.fetch_add(1, Ordering::Relaxed) + 1;
+        Tracer {
+            program: if self.program.is_empty() { clone.to_string() } else { format!("{}.{}", self.program, clone) },
+            addresses: self.addresses.clone(),
+            clones: AtomicUsize::new(0),
+            sink: Arc::clone(&self.sink)
+        }
+    }
+}
+
+/// Traces every program created from now on with a clone of `tracer`, `None` stops tracing new programs.
+/// The created programs are numbered from 1 in the order they are created.
+pub fn trace_all (tracer: Option<Tracer>) {
+    let template = tracer.map(|tracer| Tracer { program: String::new(), ..tracer });
+    *TRACE_ALL.lock().unwrap_or_else(|poisoned| poisoned.into_inner()) = template;
+}
+
+/// The tracer of a program being created, if all programs are traced.
+/// Programs created by a worker the runner gave up on aren't traced.
+pub fn for_new_program () -> Option<Tracer> {
+    if cancellation::is_cancelled() {
+        return None;
+    }
+    TRACE_ALL.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).clone()
+}
+
+/// Writes out what is still buffered of the trace of all programs.
+pub fn flush_all () -> Result<(), String> {
+    match &*TRACE_ALL.lock().unwrap_or_else(|poisoned| poisoned.into_inner()) {
+        Some(tracer) => tracer.flush(),
+        None => Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cancellation::Cancellation;
+    use crate::int_code;
+    use std::thread;
+
+    /// A sink the test can read back.
+    #[derive(Clone, Default)]
+    struct Shared(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Shared {
+        fn write (&mut self, buffer: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buffer)
+        }
+
+        fn flush (&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl Shared {
+        fn text (&self) -> String {
+            String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+        }
+    }
+
+    #[test]
+    fn records_executed_instructions_in_range () {
+        let sink = Shared::default();
+        let mut program = int_code::create_program("3,11,1001,11,5,12,109,3,204,9,99,0,0").unwrap();
+        program.set_tracer(Some(Tracer::new(sink.clone(), 0..=100)));
+        program.run_until_stopped();
+        program.push_input(7);
+        program.run_until_stopped();
+        assert_eq!(sink.text(), "\
+0 0: IN -> [11] | rb=0 read=7 write=[11]=7
+0 2: ADD [11], #5 -> [12] | rb=0 read=7,5 write=[12]=12
+0 6: ARB #3 | rb=0 read=3
+0 8: OUT [rb+9] | rb=3 read=12
+0 10: HLT | rb=3
+");
+    }
+
+    #[test]
+    fn records_the_faulting_instruction () {
+        let sink = Shared::default();
+        let mut program = int_code::create_program("1101,2,3,7,204,-1,42").unwrap();
+        program.set_tracer(Some(Tracer::new(sink.clone(), 0..=100)));
+        program.run_until_stopped();
+        assert_eq!(sink.text(), "\
+0 0: ADD #2, #3 -> [7] | rb=0 read=2,3 write=[7]=5
+0 4: OUT [rb-1] | rb=0 fault=negative address -1
+");
+        let sink = Shared::default();
+        let mut program = int_code::create_program("1,5,6,0,42").unwrap();
+        program.set_tracer(Some(Tracer::new(sink.clone(), 0..=100)));
+        program.run_until_stopped();
+        assert_eq!(sink.text(), "0 0: ADD [5], [6] -> [0] | rb=0 read=0,0 write=[0]=0\n0 4: 42 | rb=0 fault=unknown op code\n");
+    }
+
+    #[test]
+    fn clones_trace_under_their_own_number () {
+        let sink = Shared::default();
+        let mut program = int_code::create_program("104,1,104,2,99").unwrap();
+        program.set_tracer(Some(Tracer::new(sink.clone(), 2..=3)));
+        let mut clone = program.clone();
+        let mut clone_of_clone = clone.clone();
+        let mut second_clone = program.clone();
+        program.run_until_stopped();
+        clone.run_until_stopped();
+        clone_of_clone.run_until_stopped();
+        second_clone.run_until_stopped();
+        assert_eq!(sink.text(), "\
+0 2: OUT #2 | rb=0 read=2
+0.1 2: OUT #2 | rb=0 read=2
+0.1.1 2: OUT #2 | rb=0 read=2
+0.2 2: OUT #2 | rb=0 read=2
+");
+    }
+
+    #[test]
+    fn stops_tracing_once_the_worker_is_cancelled () {
+        let sink = Shared::default();
+        let worker_sink = sink.clone();
+        thread::spawn(move || {
+            let cancellation = Cancellation::default();
+            cancellation::install(cancellation.clone());
+            let mut program = int_code::create_program("1105,1,0").unwrap();
+            program.set_tracer(Some(Tracer::new(worker_sink, 0..=100)));
+            program.step();
+            program.step();
+            cancellation.cancel();
+            program.run_until_stopped();
+            program.clone().run_until_stopped();
+        }).join().unwrap();
+        assert_eq!(sink.text(), "0 0: JNZ #1, #0 | rb=0 read=1,0\n".repeat(2));
+    }
+}